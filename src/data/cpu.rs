@@ -0,0 +1,242 @@
+//! CPU data types.
+
+extern crate alloc;
+
+use alloc::vec::Vec;
+
+use core::{cmp, fmt};
+
+const WORD_BITS: usize = usize::BITS as usize;
+
+#[derive(Clone, Default)]
+///Set of CPUs, identified by index.
+///
+///Set grows dynamically, hence it is not limited by `CPU_SETSIZE`, unlike `cpu_set_t`.
+///
+///CPUs are limited to indexes below `CpuSet::MAX_CPUS`, larger ones are never stored.
+pub struct CpuSet {
+    words: Vec<usize>,
+}
+
+impl CpuSet {
+    ///Maximum number of CPUs, set can hold, with indexes from 0 to `MAX_CPUS - 1`.
+    pub const MAX_CPUS: usize = 1 << 22;
+
+    #[inline(always)]
+    ///Creates empty set.
+    pub const fn new() -> Self {
+        Self {
+            words: Vec::new(),
+        }
+    }
+
+    #[cfg(any(target_os = "linux", target_os = "android"))]
+    #[inline(always)]
+    pub(crate) fn from_words(words: Vec<usize>) -> Self {
+        Self {
+            words,
+        }
+    }
+
+    #[cfg(any(target_os = "linux", target_os = "android"))]
+    #[inline(always)]
+    ///Returns raw mask, with CPU `n` represented by bit `n % usize::BITS` of word `n / usize::BITS`.
+    pub(crate) fn words(&self) -> &[usize] {
+        &self.words
+    }
+
+    ///Parses set out of kernel's list format (e.g. `0-3,8,10-11`).
+    ///
+    ///Surrounding whitespace is ignored and empty list results in empty set.
+    ///
+    ///Returns `None` if list is invalid or refers to CPU above `CpuSet::MAX_CPUS`.
+    pub fn parse(text: &str) -> Option<Self> {
+        let mut result = Self::new();
+        let text = text.trim();
+        if text.is_empty() {
+            return Some(result);
+        }
+
+        for range in text.split(',') {
+            let (start, end) = match range.split_once('-') {
+                Some((start, end)) => (start.parse().ok()?, end.parse().ok()?),
+                None => {
+                    let cpu = range.parse().ok()?;
+                    (cpu, cpu)
+                }
+            };
+
+            if start > end || end >= Self::MAX_CPUS {
+                return None;
+            }
+
+            for cpu in start..=end {
+                result.insert(cpu);
+            }
+        }
+
+        Some(result)
+    }
+
+    #[inline]
+    ///Returns whether `cpu` is in the set.
+    pub fn contains(&self, cpu: usize) -> bool {
+        match self.words.get(cpu / WORD_BITS) {
+            Some(word) => word & (1 << (cpu % WORD_BITS)) != 0,
+            None => false,
+        }
+    }
+
+    ///Adds `cpu` to the set, returning whether it was not present before.
+    ///
+    ///Returns `false`, leaving set unchanged, if `cpu` is not below `CpuSet::MAX_CPUS`.
+    pub fn insert(&mut self, cpu: usize) -> bool {
+        if cpu >= Self::MAX_CPUS {
+            return false;
+        }
+
+        let idx = cpu / WORD_BITS;
+        if idx >= self.words.len() {
+            self.words.resize(idx + 1, 0);
+        }
+
+        let bit = 1 << (cpu % WORD_BITS);
+        let is_new = self.words[idx] & bit == 0;
+        self.words[idx] |= bit;
+        is_new
+    }
+
+    ///Removes `cpu` from the set, returning whether it was present.
+    pub fn remove(&mut self, cpu: usize) -> bool {
+        match self.words.get_mut(cpu / WORD_BITS) {
+            Some(word) => {
+                let bit = 1 << (cpu % WORD_BITS);
+                let is_present = *word & bit != 0;
+                *word &= !bit;
+                is_present
+            },
+            None => false,
+        }
+    }
+
+    #[inline]
+    ///Removes all CPUs from the set.
+    pub fn clear(&mut self) {
+        self.words.clear();
+    }
+
+    #[inline]
+    ///Returns number of CPUs in the set.
+    pub fn len(&self) -> usize {
+        self.words.iter().map(|word| word.count_ones() as usize).sum()
+    }
+
+    #[inline]
+    ///Returns whether set is empty.
+    pub fn is_empty(&self) -> bool {
+        self.words.iter().all(|word| *word == 0)
+    }
+
+    #[inline]
+    ///Returns iterator over CPUs in ascending order.
+    pub fn iter(&self) -> CpuSetIter<'_> {
+        CpuSetIter {
+            words: &self.words,
+            idx: 0,
+            current: self.words.first().copied().unwrap_or(0),
+        }
+    }
+}
+
+impl PartialEq for CpuSet {
+    fn eq(&self, other: &Self) -> bool {
+        let len = cmp::max(self.words.len(), other.words.len());
+        (0..len).all(|idx| self.words.get(idx).copied().unwrap_or(0) == other.words.get(idx).copied().unwrap_or(0))
+    }
+}
+
+impl Eq for CpuSet {}
+
+impl fmt::Display for CpuSet {
+    fn fmt(&self, fmt: &mut fmt::Formatter<'_>) -> fmt::Result {
+        let mut cpus = self.iter().peekable();
+        let mut is_first = true;
+        while let Some(start) = cpus.next() {
+            let mut end = start;
+            while cpus.peek() == Some(&(end + 1)) {
+                end += 1;
+                cpus.next();
+            }
+
+            if !is_first {
+                fmt.write_str(",")?;
+            }
+            is_first = false;
+
+            match start == end {
+                true => fmt.write_fmt(format_args!("{}", start))?,
+                false => fmt.write_fmt(format_args!("{}-{}", start, end))?,
+            }
+        }
+
+        Ok(())
+    }
+}
+
+impl fmt::Debug for CpuSet {
+    #[inline]
+    fn fmt(&self, fmt: &mut fmt::Formatter<'_>) -> fmt::Result {
+        fmt.write_fmt(format_args!("CpuSet({})", self))
+    }
+}
+
+///CPUs, that are not below `CpuSet::MAX_CPUS`, are ignored.
+impl core::iter::FromIterator<usize> for CpuSet {
+    fn from_iter<I: IntoIterator<Item = usize>>(iter: I) -> Self {
+        let mut result = Self::new();
+        result.extend(iter);
+        result
+    }
+}
+
+///CPUs, that are not below `CpuSet::MAX_CPUS`, are ignored.
+impl Extend<usize> for CpuSet {
+    fn extend<I: IntoIterator<Item = usize>>(&mut self, iter: I) {
+        for cpu in iter {
+            self.insert(cpu);
+        }
+    }
+}
+
+impl<'a> IntoIterator for &'a CpuSet {
+    type Item = usize;
+    type IntoIter = CpuSetIter<'a>;
+
+    #[inline(always)]
+    fn into_iter(self) -> Self::IntoIter {
+        self.iter()
+    }
+}
+
+///Iterator over CPUs in the set.
+pub struct CpuSetIter<'a> {
+    words: &'a [usize],
+    idx: usize,
+    current: usize,
+}
+
+impl<'a> Iterator for CpuSetIter<'a> {
+    type Item = usize;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        while self.current == 0 {
+            self.idx += 1;
+            self.current = *self.words.get(self.idx)?;
+        }
+
+        let bit = self.current.trailing_zeros() as usize;
+        //Clear lowest set bit
+        self.current &= self.current - 1;
+        Some(self.idx * WORD_BITS + bit)
+    }
+}
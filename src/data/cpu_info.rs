@@ -0,0 +1,553 @@
+//! CPU identification and feature flags.
+
+extern crate alloc;
+
+use alloc::string::String;
+use alloc::vec::Vec;
+
+#[cfg(any(target_arch = "x86", target_arch = "x86_64"))]
+use core::sync::atomic::{AtomicU64, Ordering};
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+///CPU feature, commonly used to select SIMD implementation.
+pub enum Feature {
+    ///x86 SSE.
+    Sse,
+    ///x86 SSE2.
+    Sse2,
+    ///x86 SSE3.
+    Sse3,
+    ///x86 SSSE3.
+    Ssse3,
+    ///x86 SSE4.1.
+    Sse41,
+    ///x86 SSE4.2.
+    Sse42,
+    ///x86 POPCNT.
+    Popcnt,
+    ///x86 LZCNT.
+    Lzcnt,
+    ///x86 MOVBE.
+    Movbe,
+    ///x86 PCLMULQDQ.
+    Pclmulqdq,
+    ///AES instructions (x86 AES-NI or ARM crypto extension).
+    Aes,
+    ///x86 AVX.
+    Avx,
+    ///x86 AVX2.
+    Avx2,
+    ///x86 FMA3.
+    Fma,
+    ///x86 F16C.
+    F16c,
+    ///x86 BMI1.
+    Bmi1,
+    ///x86 BMI2.
+    Bmi2,
+    ///x86 ADX.
+    Adx,
+    ///x86 RDRAND.
+    Rdrand,
+    ///x86 RDSEED.
+    Rdseed,
+    ///x86 SHA extensions.
+    Sha,
+    ///x86 AVX-512 Foundation.
+    Avx512f,
+    ///x86 AVX-512 Doubleword and Quadword.
+    Avx512dq,
+    ///x86 AVX-512 Conflict Detection.
+    Avx512cd,
+    ///x86 AVX-512 Byte and Word.
+    Avx512bw,
+    ///x86 AVX-512 Vector Length extensions.
+    Avx512vl,
+    ///x86 AVX-512 Vector Neural Network Instructions.
+    Avx512Vnni,
+    ///x86 vector AES.
+    Vaes,
+    ///x86 Galois Field instructions.
+    Gfni,
+    ///ARM Advanced SIMD (NEON).
+    Neon,
+    ///ARM CRC32 instructions.
+    Crc32,
+    ///ARM polynomial multiply long.
+    Pmull,
+    ///ARM SHA-1 instructions.
+    Sha1,
+    ///ARM SHA-256 instructions.
+    Sha2,
+    ///ARM SHA-3 instructions.
+    Sha3,
+    ///ARM Large System Extensions atomics.
+    Atomics,
+    ///ARM half precision floating point.
+    Fp16,
+    ///ARM dot product instructions.
+    DotProd,
+    ///ARM Scalable Vector Extension.
+    Sve,
+    ///ARM Scalable Vector Extension 2.
+    Sve2,
+    ///CPU runs under hypervisor.
+    Hypervisor,
+}
+
+impl Feature {
+    ///Returns name of the flag, as used by `/proc/cpuinfo`.
+    pub const fn name(&self) -> &'static str {
+        match self {
+            Feature::Sse => "sse",
+            Feature::Sse2 => "sse2",
+            Feature::Sse3 => "pni",
+            Feature::Ssse3 => "ssse3",
+            Feature::Sse41 => "sse4_1",
+            Feature::Sse42 => "sse4_2",
+            Feature::Popcnt => "popcnt",
+            Feature::Lzcnt => "abm",
+            Feature::Movbe => "movbe",
+            Feature::Pclmulqdq => "pclmulqdq",
+            Feature::Aes => "aes",
+            Feature::Avx => "avx",
+            Feature::Avx2 => "avx2",
+            Feature::Fma => "fma",
+            Feature::F16c => "f16c",
+            Feature::Bmi1 => "bmi1",
+            Feature::Bmi2 => "bmi2",
+            Feature::Adx => "adx",
+            Feature::Rdrand => "rdrand",
+            Feature::Rdseed => "rdseed",
+            Feature::Sha => "sha_ni",
+            Feature::Avx512f => "avx512f",
+            Feature::Avx512dq => "avx512dq",
+            Feature::Avx512cd => "avx512cd",
+            Feature::Avx512bw => "avx512bw",
+            Feature::Avx512vl => "avx512vl",
+            Feature::Avx512Vnni => "avx512_vnni",
+            Feature::Vaes => "vaes",
+            Feature::Gfni => "gfni",
+            #[cfg(target_arch = "arm")]
+            Feature::Neon => "neon",
+            #[cfg(not(target_arch = "arm"))]
+            Feature::Neon => "asimd",
+            Feature::Crc32 => "crc32",
+            Feature::Pmull => "pmull",
+            Feature::Sha1 => "sha1",
+            Feature::Sha2 => "sha2",
+            Feature::Sha3 => "sha3",
+            Feature::Atomics => "atomics",
+            Feature::Fp16 => "fphp",
+            Feature::DotProd => "asimddp",
+            Feature::Sve => "sve",
+            Feature::Sve2 => "sve2",
+            Feature::Hypervisor => "hypervisor",
+        }
+    }
+}
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+///CPU identification.
+pub struct Info {
+    ///Index of CPU, information belongs to.
+    pub cpu: usize,
+    ///Vendor (e.g. `GenuineIntel`, `AuthenticAMD` or ARM implementer's name).
+    pub vendor: Option<String>,
+    ///Model name (e.g. x86 brand string).
+    pub model_name: Option<String>,
+    ///Family on x86, architecture version on ARM.
+    pub family: u32,
+    ///Model on x86, part number on ARM.
+    pub model: u32,
+    ///Stepping on x86, revision on ARM.
+    pub stepping: u32,
+    ///ARM implementer code.
+    pub implementer: Option<u32>,
+    ///ARM variant.
+    pub variant: Option<u32>,
+    ///Microcode revision, if reported by kernel.
+    pub microcode: Option<u64>,
+    flags: Vec<String>,
+}
+
+pub(crate) fn parse_number(value: &str) -> Option<u64> {
+    match value.strip_prefix("0x") {
+        Some(value) => u64::from_str_radix(value, 16).ok(),
+        None => value.parse().ok(),
+    }
+}
+
+const fn arm_implementer(code: u32) -> Option<&'static str> {
+    match code {
+        0x41 => Some("ARM"),
+        0x42 => Some("Broadcom"),
+        0x43 => Some("Cavium"),
+        0x46 => Some("Fujitsu"),
+        0x48 => Some("HiSilicon"),
+        0x4e => Some("NVIDIA"),
+        0x50 => Some("APM"),
+        0x51 => Some("Qualcomm"),
+        0x53 => Some("Samsung"),
+        0x56 => Some("Marvell"),
+        0x61 => Some("Apple"),
+        0x69 => Some("Intel"),
+        0xc0 => Some("Ampere"),
+        _ => None,
+    }
+}
+
+impl Info {
+    pub(crate) const fn empty(cpu: usize) -> Self {
+        Self {
+            cpu,
+            vendor: None,
+            model_name: None,
+            family: 0,
+            model: 0,
+            stepping: 0,
+            implementer: None,
+            variant: None,
+            microcode: None,
+            flags: Vec::new(),
+        }
+    }
+
+    ///Parses content of `/proc/cpuinfo`, returning information for each CPU.
+    ///
+    ///Both x86 (`vendor_id`, `flags`) and ARM (`CPU implementer`, `CPU part`, `Features`) formats are supported.
+    pub fn parse(content: &[u8]) -> Vec<Self> {
+        let content = String::from_utf8_lossy(content);
+        let mut result = Vec::new();
+        let mut current: Option<Self> = None;
+
+        for line in content.lines() {
+            let (key, value) = match line.split_once(':') {
+                Some((key, value)) => (key.trim(), value.trim()),
+                None => continue,
+            };
+
+            if key == "processor" {
+                //Old ARM kernels put model name into `Processor` field, rather than index
+                if let Ok(cpu) = value.parse() {
+                    result.extend(current.take());
+                    current = Some(Self::empty(cpu));
+                }
+                continue;
+            }
+
+            let info = match current.as_mut() {
+                Some(info) => info,
+                None => continue,
+            };
+            let number = parse_number(value);
+
+            match key {
+                "vendor_id" => info.vendor = Some(value.into()),
+                "model name" | "cpu model" | "uarch" => info.model_name = Some(value.into()),
+                "cpu family" | "CPU architecture" => info.family = number.unwrap_or(0) as u32,
+                "model" | "CPU part" => info.model = number.unwrap_or(0) as u32,
+                "stepping" | "CPU revision" => info.stepping = number.unwrap_or(0) as u32,
+                "microcode" => info.microcode = number,
+                "CPU variant" => info.variant = number.map(|number| number as u32),
+                "CPU implementer" => if let Some(code) = number {
+                    info.implementer = Some(code as u32);
+                    info.vendor = arm_implementer(code as u32).map(Into::into);
+                },
+                "flags" | "Features" | "isa" => info.flags = value.split_whitespace().map(Into::into).collect(),
+                _ => (),
+            }
+        }
+
+        result.extend(current);
+        result
+    }
+
+    #[inline]
+    ///Returns iterator over feature flags, as named by `/proc/cpuinfo`.
+    pub fn flags(&self) -> impl Iterator<Item = &str> {
+        self.flags.iter().map(String::as_str)
+    }
+
+    #[inline]
+    ///Returns whether CPU has feature flag with specified name, as named by `/proc/cpuinfo`.
+    pub fn has_flag(&self, name: &str) -> bool {
+        self.flags.iter().any(|flag| flag == name)
+    }
+
+    #[inline]
+    ///Returns whether CPU has specified feature.
+    pub fn has_feature(&self, feature: Feature) -> bool {
+        self.has_flag(feature.name())
+    }
+}
+
+#[cfg(any(target_arch = "x86", target_arch = "x86_64"))]
+#[allow(unused_unsafe)]
+pub(crate) mod x86 {
+    extern crate alloc;
+
+    use alloc::string::String;
+    use alloc::vec::Vec;
+
+    #[cfg(target_arch = "x86")]
+    use core::arch::x86::{CpuidResult, __cpuid_count, _xgetbv};
+    #[cfg(target_arch = "x86_64")]
+    use core::arch::x86_64::{CpuidResult, __cpuid_count, _xgetbv};
+
+    use super::{Info, Feature};
+
+    pub(crate) const EXT_LEAF: u32 = 0x8000_0000;
+    //XMM and YMM state, saved by OS
+    const XCR0_AVX: u64 = 0b110;
+    //Opmask and ZMM state, saved by OS
+    const XCR0_AVX512: u64 = 0b1110_0000;
+
+    const LEAF1_EDX: [(u32, &str); 10] = [
+        (0, "fpu"), (4, "tsc"), (8, "cx8"), (15, "cmov"), (19, "clflush"),
+        (23, "mmx"), (24, "fxsr"), (25, "sse"), (26, "sse2"), (28, "ht"),
+    ];
+    const LEAF1_ECX: [(u32, &str); 15] = [
+        (0, "pni"), (1, "pclmulqdq"), (9, "ssse3"), (12, "fma"), (13, "cx16"), (19, "sse4_1"), (20, "sse4_2"),
+        (22, "movbe"), (23, "popcnt"), (25, "aes"), (26, "xsave"), (28, "avx"), (29, "f16c"), (30, "rdrand"), (31, "hypervisor"),
+    ];
+    const LEAF7_EBX: [(u32, &str); 13] = [
+        (3, "bmi1"), (5, "avx2"), (8, "bmi2"), (9, "erms"), (16, "avx512f"), (17, "avx512dq"), (18, "rdseed"),
+        (19, "adx"), (21, "avx512ifma"), (28, "avx512cd"), (29, "sha_ni"), (30, "avx512bw"), (31, "avx512vl"),
+    ];
+    const LEAF7_ECX: [(u32, &str); 6] = [
+        (1, "avx512vbmi"), (8, "gfni"), (9, "vaes"), (10, "vpclmulqdq"), (11, "avx512_vnni"), (14, "avx512_vpopcntdq"),
+    ];
+    const EXT_ECX: [(u32, &str); 3] = [(0, "lahf_lm"), (5, "abm"), (6, "sse4a")];
+    const EXT_EDX: [(u32, &str); 4] = [(11, "syscall"), (20, "nx"), (27, "rdtscp"), (29, "lm")];
+
+    #[inline(always)]
+    pub(crate) fn cpuid_count(leaf: u32, subleaf: u32) -> CpuidResult {
+        unsafe {
+            __cpuid_count(leaf, subleaf)
+        }
+    }
+
+    #[inline(always)]
+    pub(crate) fn cpuid(leaf: u32) -> CpuidResult {
+        cpuid_count(leaf, 0)
+    }
+
+    ///Returns extended control register, describing state saved by OS on context switch.
+    ///
+    ///Vector extensions are usable only if OS saves their registers.
+    fn xcr0(leaf1_ecx: u32) -> u64 {
+        const OSXSAVE: u32 = 1 << 27;
+        match leaf1_ecx & OSXSAVE {
+            0 => 0,
+            _ => unsafe {
+                _xgetbv(0)
+            },
+        }
+    }
+
+    #[derive(Clone, Copy)]
+    enum Register {
+        Ebx,
+        Ecx,
+        Edx,
+    }
+
+    ///Returns CPUID leaf, register and bit, reporting `feature`.
+    const fn location(feature: Feature) -> Option<(u32, Register, u32)> {
+        match feature {
+            Feature::Sse => Some((1, Register::Edx, 25)),
+            Feature::Sse2 => Some((1, Register::Edx, 26)),
+            Feature::Sse3 => Some((1, Register::Ecx, 0)),
+            Feature::Pclmulqdq => Some((1, Register::Ecx, 1)),
+            Feature::Ssse3 => Some((1, Register::Ecx, 9)),
+            Feature::Fma => Some((1, Register::Ecx, 12)),
+            Feature::Sse41 => Some((1, Register::Ecx, 19)),
+            Feature::Sse42 => Some((1, Register::Ecx, 20)),
+            Feature::Movbe => Some((1, Register::Ecx, 22)),
+            Feature::Popcnt => Some((1, Register::Ecx, 23)),
+            Feature::Aes => Some((1, Register::Ecx, 25)),
+            Feature::Avx => Some((1, Register::Ecx, 28)),
+            Feature::F16c => Some((1, Register::Ecx, 29)),
+            Feature::Rdrand => Some((1, Register::Ecx, 30)),
+            Feature::Hypervisor => Some((1, Register::Ecx, 31)),
+            Feature::Bmi1 => Some((7, Register::Ebx, 3)),
+            Feature::Avx2 => Some((7, Register::Ebx, 5)),
+            Feature::Bmi2 => Some((7, Register::Ebx, 8)),
+            Feature::Avx512f => Some((7, Register::Ebx, 16)),
+            Feature::Avx512dq => Some((7, Register::Ebx, 17)),
+            Feature::Rdseed => Some((7, Register::Ebx, 18)),
+            Feature::Adx => Some((7, Register::Ebx, 19)),
+            Feature::Avx512cd => Some((7, Register::Ebx, 28)),
+            Feature::Sha => Some((7, Register::Ebx, 29)),
+            Feature::Avx512bw => Some((7, Register::Ebx, 30)),
+            Feature::Avx512vl => Some((7, Register::Ebx, 31)),
+            Feature::Gfni => Some((7, Register::Ecx, 8)),
+            Feature::Vaes => Some((7, Register::Ecx, 9)),
+            Feature::Avx512Vnni => Some((7, Register::Ecx, 11)),
+            Feature::Lzcnt => Some((EXT_LEAF + 1, Register::Ecx, 5)),
+            _ => None,
+        }
+    }
+
+    ///Checks single feature, querying only CPUID leaf it is reported by.
+    pub(super) fn has_feature(feature: Feature) -> bool {
+        let (leaf, register, bit) = match location(feature) {
+            Some(location) => location,
+            None => return false,
+        };
+
+        //Maximum leaf of either basic or extended range
+        if cpuid(leaf & EXT_LEAF).eax < leaf {
+            return false;
+        }
+
+        let result = cpuid(leaf);
+        let value = match register {
+            Register::Ebx => result.ebx,
+            Register::Ecx => result.ecx,
+            Register::Edx => result.edx,
+        };
+        if value & (1 << bit) == 0 {
+            return false;
+        }
+
+        let required = match feature {
+            Feature::Avx512f | Feature::Avx512dq | Feature::Avx512cd | Feature::Avx512bw | Feature::Avx512vl | Feature::Avx512Vnni => XCR0_AVX | XCR0_AVX512,
+            Feature::Avx | Feature::Avx2 | Feature::Fma | Feature::F16c | Feature::Vaes => XCR0_AVX,
+            _ => return true,
+        };
+        let leaf1_ecx = match leaf {
+            1 => result.ecx,
+            _ => cpuid(1).ecx,
+        };
+        xcr0(leaf1_ecx) & required == required
+    }
+
+    fn push_flags(flags: &mut Vec<String>, value: u32, table: &[(u32, &str)], is_enabled: impl Fn(&str) -> bool) {
+        for (bit, name) in table.iter() {
+            if value & (1 << bit) != 0 && is_enabled(name) {
+                flags.push((*name).into());
+            }
+        }
+    }
+
+    fn push_bytes(buffer: &mut Vec<u8>, values: &[u32]) {
+        for value in values {
+            buffer.extend_from_slice(&value.to_le_bytes());
+        }
+    }
+
+    ///Identifies CPU, the calling thread runs on, using CPUID.
+    pub(crate) fn info(cpu: usize) -> Info {
+        let mut result = Info::empty(cpu);
+
+        let leaf = cpuid(0);
+        let max_leaf = leaf.eax;
+        let mut vendor = Vec::with_capacity(12);
+        push_bytes(&mut vendor, &[leaf.ebx, leaf.edx, leaf.ecx]);
+        result.vendor = String::from_utf8(vendor).ok();
+
+        let leaf1 = cpuid(1);
+        let family = (leaf1.eax >> 8) & 0xf;
+        let model = (leaf1.eax >> 4) & 0xf;
+        result.stepping = leaf1.eax & 0xf;
+        result.family = match family {
+            0xf => family + ((leaf1.eax >> 20) & 0xff),
+            family => family,
+        };
+        result.model = match family {
+            0x6 | 0xf => model | (((leaf1.eax >> 16) & 0xf) << 4),
+            _ => model,
+        };
+
+        let xcr0 = xcr0(leaf1.ecx);
+        let has_avx = xcr0 & XCR0_AVX == XCR0_AVX;
+        let has_avx512 = has_avx && xcr0 & XCR0_AVX512 == XCR0_AVX512;
+        let is_enabled = |name: &str| match name {
+            name if name.starts_with("avx512") => has_avx512,
+            "avx" | "avx2" | "fma" | "f16c" | "vaes" | "vpclmulqdq" => has_avx,
+            _ => true,
+        };
+
+        let flags = &mut result.flags;
+        push_flags(flags, leaf1.edx, &LEAF1_EDX, &is_enabled);
+        push_flags(flags, leaf1.ecx, &LEAF1_ECX, &is_enabled);
+
+        if max_leaf >= 7 {
+            let leaf7 = cpuid(7);
+            push_flags(flags, leaf7.ebx, &LEAF7_EBX, &is_enabled);
+            push_flags(flags, leaf7.ecx, &LEAF7_ECX, &is_enabled);
+        }
+
+        let max_ext_leaf = cpuid(EXT_LEAF).eax;
+        if max_ext_leaf > EXT_LEAF {
+            let ext = cpuid(EXT_LEAF + 1);
+            push_flags(flags, ext.ecx, &EXT_ECX, &is_enabled);
+            push_flags(flags, ext.edx, &EXT_EDX, &is_enabled);
+        }
+
+        if max_ext_leaf >= EXT_LEAF + 4 {
+            let mut brand = Vec::with_capacity(48);
+            for leaf in EXT_LEAF + 2..=EXT_LEAF + 4 {
+                let leaf = cpuid(leaf);
+                push_bytes(&mut brand, &[leaf.eax, leaf.ebx, leaf.ecx, leaf.edx]);
+            }
+
+            let len = brand.iter().position(|byte| *byte == 0).unwrap_or(brand.len());
+            result.model_name = core::str::from_utf8(&brand[..len]).ok()
+                                                                  .map(str::trim)
+                                                                  .filter(|name| !name.is_empty())
+                                                                  .map(Into::into);
+        }
+
+        result
+    }
+}
+
+#[cfg(any(target_arch = "x86", target_arch = "x86_64"))]
+///Features, that were already checked, one bit per `Feature`.
+static CHECKED_FEATURES: AtomicU64 = AtomicU64::new(0);
+#[cfg(any(target_arch = "x86", target_arch = "x86_64"))]
+///Features, supported by CPU, one bit per `Feature`.
+static FEATURES: AtomicU64 = AtomicU64::new(0);
+
+#[cfg(any(target_arch = "x86", target_arch = "x86_64"))]
+///Returns whether CPU has specified feature.
+///
+///Uses CPUID leaf, reporting the feature, and caches result, hence it is cheap to call repeatedly.
+pub fn has_feature(feature: Feature) -> bool {
+    let bit = 1u64 << feature as u32;
+    if CHECKED_FEATURES.load(Ordering::Acquire) & bit == 0 {
+        if x86::has_feature(feature) {
+            FEATURES.fetch_or(bit, Ordering::Relaxed);
+        }
+        CHECKED_FEATURES.fetch_or(bit, Ordering::Release);
+    }
+
+    FEATURES.load(Ordering::Relaxed) & bit != 0
+}
+
+#[cfg(all(not(any(target_arch = "x86", target_arch = "x86_64")), not(any(target_os = "linux", target_os = "android"))))]
+///Returns whether CPU has specified feature.
+///
+///Feature detection is not supported on this platform, hence it is always `false`.
+pub fn has_feature(_feature: Feature) -> bool {
+    false
+}
+
+#[cfg(all(any(target_arch = "x86", target_arch = "x86_64"), not(any(target_os = "linux", target_os = "android"))))]
+///Identifies CPU, the calling thread runs on.
+///
+///Uses CPUID instruction. Index of CPU is not known on this platform, hence `Info::cpu` is always 0.
+pub fn info() -> Option<Info> {
+    Some(x86::info(0))
+}
+
+#[cfg(all(not(any(target_arch = "x86", target_arch = "x86_64")), not(any(target_os = "linux", target_os = "android"))))]
+///Identifies CPU, the calling thread runs on.
+///
+///CPU identification is not supported on this platform, hence it is always `None`.
+pub fn info() -> Option<Info> {
+    None
+}
@@ -1,7 +1,70 @@
 const MAX_SIZE: usize = 255;
+const MAX_LABEL_SIZE: usize = 63;
+const UNAME_FIELD_SIZE: usize = 65;
 
+extern crate alloc;
+
+use alloc::string::String;
+use alloc::vec::Vec;
 use core::{hash, fmt, cmp};
 
+#[inline]
+fn slice_c_str(input: &[u8]) -> &[u8] {
+    let mut idx = 0;
+    while idx < input.len() {
+        if input[idx] == 0 {
+            return &input[..idx]
+        }
+        idx += 1;
+    }
+
+    input
+}
+
+#[inline]
+fn as_str(bytes: &[u8]) -> Result<&str, &[u8]> {
+    #[cold]
+    #[inline(never)]
+    fn fail<T>(res: T) -> T {
+        res
+    }
+
+    match core::str::from_utf8(bytes) {
+        Ok(res) => Ok(res),
+        Err(_) => fail(Err(bytes)),
+    }
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+///Reason why host name is not valid.
+pub enum HostNameError {
+    ///Name is empty.
+    Empty,
+    ///Name is longer than 255 characters.
+    TooLong,
+    ///Name contains empty label (e.g. two consecutive dots).
+    EmptyLabel,
+    ///Label is longer than 63 characters.
+    LabelTooLong,
+    ///Label starts or ends with hyphen.
+    InvalidHyphen,
+    ///Name contains character other than ASCII letter, digit, hyphen or dot.
+    InvalidCharacter,
+}
+
+impl fmt::Display for HostNameError {
+    fn fmt(&self, fmt: &mut fmt::Formatter) -> fmt::Result {
+        match self {
+            HostNameError::Empty => fmt.write_str("host name is empty"),
+            HostNameError::TooLong => fmt.write_str("host name is longer than 255 characters"),
+            HostNameError::EmptyLabel => fmt.write_str("host name contains empty label"),
+            HostNameError::LabelTooLong => fmt.write_str("host name label is longer than 63 characters"),
+            HostNameError::InvalidHyphen => fmt.write_str("host name label starts or ends with hyphen"),
+            HostNameError::InvalidCharacter => fmt.write_str("host name contains invalid character"),
+        }
+    }
+}
+
 #[derive(Clone)]
 ///Host name, limited to 255 characters.
 ///
@@ -41,18 +104,58 @@ impl HostName {
         }
     }
 
-    #[inline]
-    ///Returns name as byte slice
-    pub fn as_bytes(&self) -> &[u8] {
-        let mut idx = 0;
-        while idx < self.name.len() {
-            if self.name[idx] == 0 {
-                return &self.name[..idx]
+    ///Parses name, validating it according to RFC 1123.
+    ///
+    ///Name must consist of dot separated labels, each up to 63 characters long, containing only
+    ///ASCII letters, digits and hyphens, which cannot start or end label.
+    pub fn parse(name: &str) -> Result<Self, HostNameError> {
+        if name.is_empty() {
+            return Err(HostNameError::Empty);
+        } else if name.len() > MAX_SIZE {
+            return Err(HostNameError::TooLong);
+        }
+
+        for label in name.split('.') {
+            if label.is_empty() {
+                return Err(HostNameError::EmptyLabel);
+            } else if label.len() > MAX_LABEL_SIZE {
+                return Err(HostNameError::LabelTooLong);
+            } else if !label.bytes().all(|byte| byte.is_ascii_alphanumeric() || byte == b'-') {
+                return Err(HostNameError::InvalidCharacter);
+            } else if label.starts_with('-') || label.ends_with('-') {
+                return Err(HostNameError::InvalidHyphen);
             }
-            idx += 1;
         }
 
-        self.name.as_slice()
+        Ok(Self::from_slice(name.as_bytes()))
+    }
+
+    #[inline]
+    ///Initializes name from byte slice, truncating it to capacity.
+    pub(crate) fn from_slice(bytes: &[u8]) -> Self {
+        let mut name = [0u8; MAX_SIZE];
+        let len = cmp::min(bytes.len(), MAX_SIZE);
+        name[..len].copy_from_slice(&bytes[..len]);
+        Self::name(name)
+    }
+
+    #[inline]
+    ///Returns domain part of the name, i.e. everything after first dot.
+    ///
+    ///Returns `None` if name has no domain.
+    pub fn domain(&self) -> Option<HostName> {
+        let bytes = self.as_bytes();
+        let dot = bytes.iter().position(|byte| *byte == b'.')?;
+        match &bytes[dot + 1..] {
+            [] => None,
+            domain => Some(Self::from_slice(domain)),
+        }
+    }
+
+    #[inline]
+    ///Returns name as byte slice
+    pub fn as_bytes(&self) -> &[u8] {
+        slice_c_str(&self.name)
     }
 
     #[inline]
@@ -62,17 +165,7 @@ impl HostName {
     ///
     ///On windows it never fails.
     pub fn as_str(&self) -> Result<&str, &[u8]> {
-        #[cold]
-        #[inline(never)]
-        fn fail<T>(res: T) -> T {
-            res
-        }
-
-        let bytes = self.as_bytes();
-        match core::str::from_utf8(bytes) {
-            Ok(res) => Ok(res),
-            Err(_) => fail(Err(bytes)),
-        }
+        as_str(self.as_bytes())
     }
 }
 
@@ -187,3 +280,389 @@ impl fmt::Display for HostName {
         }
     }
 }
+
+#[derive(Clone, PartialEq, Eq, Hash)]
+///Kernel and operating system identity, as reported by `uname`.
+///
+///Each field is limited to 64 characters and accessors follow `HostName::as_str` semantics:
+///content is returned as string, if it is valid utf-8, otherwise as byte slice.
+pub struct Uname {
+    pub(crate) sysname: [u8; UNAME_FIELD_SIZE],
+    pub(crate) release: [u8; UNAME_FIELD_SIZE],
+    pub(crate) version: [u8; UNAME_FIELD_SIZE],
+    pub(crate) machine: [u8; UNAME_FIELD_SIZE],
+    pub(crate) domainname: [u8; UNAME_FIELD_SIZE],
+}
+
+impl Uname {
+    #[inline(always)]
+    ///Creates new instance with all fields empty.
+    pub const fn new() -> Self {
+        Self {
+            sysname: [0; UNAME_FIELD_SIZE],
+            release: [0; UNAME_FIELD_SIZE],
+            version: [0; UNAME_FIELD_SIZE],
+            machine: [0; UNAME_FIELD_SIZE],
+            domainname: [0; UNAME_FIELD_SIZE],
+        }
+    }
+
+    #[inline(always)]
+    ///Returns field size limit.
+    pub const fn capacity() -> usize {
+        UNAME_FIELD_SIZE - 1
+    }
+
+    #[inline]
+    ///Copies null terminated `src` into `dest`, truncating it if necessary.
+    pub(crate) fn copy_field<T: Copy + Into<i16>>(dest: &mut [u8; UNAME_FIELD_SIZE], src: &[T]) {
+        for (dest, src) in dest[..Self::capacity()].iter_mut().zip(src.iter()) {
+            let src: i16 = (*src).into();
+            if src == 0 {
+                break;
+            }
+            *dest = src as u8;
+        }
+    }
+
+    #[inline]
+    ///Returns operating system name (e.g. `Linux`).
+    pub fn sysname(&self) -> Result<&str, &[u8]> {
+        as_str(slice_c_str(&self.sysname))
+    }
+
+    #[inline]
+    ///Returns operating system release (e.g. `5.15.0-86-generic`).
+    pub fn release(&self) -> Result<&str, &[u8]> {
+        as_str(slice_c_str(&self.release))
+    }
+
+    #[inline]
+    ///Returns operating system version, commonly kernel build information.
+    pub fn version(&self) -> Result<&str, &[u8]> {
+        as_str(slice_c_str(&self.version))
+    }
+
+    #[inline]
+    ///Returns hardware identifier (e.g. `x86_64`).
+    pub fn machine(&self) -> Result<&str, &[u8]> {
+        as_str(slice_c_str(&self.machine))
+    }
+
+    #[inline]
+    ///Returns NIS or YP domain name.
+    ///
+    ///Empty on systems, which do not provide it, or when it is not set.
+    pub fn domainname(&self) -> Result<&str, &[u8]> {
+        as_str(slice_c_str(&self.domainname))
+    }
+}
+
+impl fmt::Debug for Uname {
+    fn fmt(&self, fmt: &mut fmt::Formatter) -> fmt::Result {
+        fmt.debug_struct("Uname").field("sysname", &self.sysname())
+                                 .field("release", &self.release())
+                                 .field("version", &self.version())
+                                 .field("machine", &self.machine())
+                                 .field("domainname", &self.domainname())
+                                 .finish()
+    }
+}
+
+#[derive(Debug, Clone, Default, PartialEq, Eq)]
+///Operating system identification, as described by `os-release` specification.
+///
+///See <https://www.freedesktop.org/software/systemd/man/os-release.html>
+pub struct OsRelease {
+    entries: Vec<(String, String)>,
+}
+
+impl OsRelease {
+    ///Parses content of `os-release` file.
+    ///
+    ///Values can be quoted with single or double quotes and use shell-like escapes.
+    ///Comments, empty lines and lines without valid assignment are ignored.
+    pub fn parse(content: &[u8]) -> Self {
+        let mut result = Self::default();
+
+        for line in content.split(|byte| *byte == b'\n') {
+            let line = match core::str::from_utf8(line) {
+                Ok(line) => line.trim(),
+                Err(_) => continue,
+            };
+
+            if line.is_empty() || line.starts_with('#') {
+                continue;
+            }
+
+            let (key, value) = match line.split_once('=') {
+                Some((key, value)) => (key.trim(), value.trim()),
+                None => continue,
+            };
+
+            if key.is_empty() || !key.bytes().all(|byte| byte.is_ascii_alphanumeric() || byte == b'_') {
+                continue;
+            }
+
+            let value = match Self::unquote(value) {
+                Some(value) => value,
+                None => continue,
+            };
+
+            //Later assignment overrides previous one
+            match result.entries.iter_mut().find(|(entry_key, _)| entry_key == key) {
+                Some(entry) => entry.1 = value,
+                None => result.entries.push((key.into(), value)),
+            }
+        }
+
+        result
+    }
+
+    ///Removes quotes and escapes, returning `None` if quote is not closed.
+    fn unquote(value: &str) -> Option<String> {
+        let mut result = String::with_capacity(value.len());
+        let mut quote = None;
+        let mut chars = value.chars();
+
+        while let Some(ch) = chars.next() {
+            match (quote, ch) {
+                (None, '\'') | (None, '"') => quote = Some(ch),
+                (Some(current), ch) if current == ch => quote = None,
+                (Some('\''), ch) => result.push(ch),
+                (Some(_), '\\') => match chars.next()? {
+                    //Inside double quotes, only these characters are escaped
+                    escaped @ ('"' | '\\' | '$' | '`') => result.push(escaped),
+                    escaped => {
+                        result.push('\\');
+                        result.push(escaped);
+                    },
+                },
+                (None, '\\') => result.push(chars.next()?),
+                (_, ch) => result.push(ch),
+            }
+        }
+
+        match quote {
+            Some(_) => None,
+            None => Some(result),
+        }
+    }
+
+    #[inline]
+    ///Returns value of arbitrary `key`.
+    pub fn get(&self, key: &str) -> Option<&str> {
+        self.entries.iter().find(|(entry_key, _)| entry_key == key).map(|(_, value)| value.as_str())
+    }
+
+    #[inline]
+    ///Returns iterator over all keys and values.
+    pub fn iter(&self) -> impl Iterator<Item = (&str, &str)> {
+        self.entries.iter().map(|(key, value)| (key.as_str(), value.as_str()))
+    }
+
+    #[inline]
+    ///Returns `ID`, lower-case operating system identifier (e.g. `debian`).
+    ///
+    ///Defaults to `linux` as required by specification.
+    pub fn id(&self) -> &str {
+        self.get("ID").unwrap_or("linux")
+    }
+
+    #[inline]
+    ///Returns iterator over `ID_LIKE`, space separated list of operating systems, this one is derived from.
+    pub fn id_like(&self) -> impl Iterator<Item = &str> {
+        self.get("ID_LIKE").unwrap_or("").split_whitespace()
+    }
+
+    #[inline]
+    ///Returns `VERSION_ID`, operating system version (e.g. `22.04`).
+    pub fn version_id(&self) -> Option<&str> {
+        self.get("VERSION_ID")
+    }
+
+    #[inline]
+    ///Returns `PRETTY_NAME`, operating system name suitable for presentation.
+    ///
+    ///Defaults to `Linux` as required by specification.
+    pub fn pretty_name(&self) -> &str {
+        self.get("PRETTY_NAME").unwrap_or("Linux")
+    }
+
+    #[inline]
+    ///Returns `VERSION_CODENAME`, release code name (e.g. `jammy`).
+    pub fn version_codename(&self) -> Option<&str> {
+        self.get("VERSION_CODENAME")
+    }
+
+    #[inline]
+    ///Returns `BUILD_ID`, identifier of system image.
+    pub fn build_id(&self) -> Option<&str> {
+        self.get("BUILD_ID")
+    }
+
+    #[inline]
+    ///Returns `VARIANT_ID`, identifier of operating system variant (e.g. `server`).
+    pub fn variant_id(&self) -> Option<&str> {
+        self.get("VARIANT_ID")
+    }
+
+    #[inline]
+    ///Returns whether operating system is `id` or derived from it.
+    pub fn is_like(&self, id: &str) -> bool {
+        self.id() == id || self.id_like().any(|like| like == id)
+    }
+}
+
+#[derive(Debug, Clone, Copy, Default, PartialEq)]
+///System load averages.
+pub struct LoadAverage {
+    ///Average over last minute.
+    pub one: f64,
+    ///Average over last 5 minutes.
+    pub five: f64,
+    ///Average over last 15 minutes.
+    pub fifteen: f64,
+    ///Number of currently runnable tasks, if available.
+    pub running: Option<u32>,
+    ///Total number of tasks, if available.
+    pub total: Option<u32>,
+    ///Last allocated PID, if available.
+    pub last_pid: Option<u32>,
+}
+
+impl LoadAverage {
+    ///Parses content of `/proc/loadavg`.
+    pub fn parse(content: &[u8]) -> Option<Self> {
+        let content = core::str::from_utf8(content).ok()?;
+        let mut parts = content.split_whitespace();
+
+        let one = parts.next()?.parse().ok()?;
+        let five = parts.next()?.parse().ok()?;
+        let fifteen = parts.next()?.parse().ok()?;
+        let (running, total) = match parts.next().and_then(|tasks| tasks.split_once('/')) {
+            Some((running, total)) => (running.parse().ok(), total.parse().ok()),
+            None => (None, None),
+        };
+        let last_pid = parts.next().and_then(|pid| pid.parse().ok());
+
+        Some(Self {
+            one,
+            five,
+            fifteen,
+            running,
+            total,
+            last_pid,
+        })
+    }
+}
+
+#[derive(Clone, Copy, PartialEq, Eq, Hash, PartialOrd, Ord)]
+///128-bit identifier, such as machine or boot ID.
+///
+///## fmt
+///
+///- `Debug` and `Display` traits output 32 lower-case hexadecimal characters.
+pub struct Id128 {
+    bytes: [u8; 16],
+}
+
+impl Id128 {
+    #[inline(always)]
+    ///Creates identifier from raw bytes.
+    pub const fn new(bytes: [u8; 16]) -> Self {
+        Self {
+            bytes
+        }
+    }
+
+    #[inline(always)]
+    ///Returns raw bytes.
+    pub const fn as_bytes(&self) -> &[u8; 16] {
+        &self.bytes
+    }
+
+    #[inline]
+    ///Returns whether all bytes are zero.
+    pub fn is_null(&self) -> bool {
+        self.bytes.iter().all(|byte| *byte == 0)
+    }
+
+    ///Parses identifier from 32 hexadecimal characters or UUID formatted string.
+    ///
+    ///Surrounding whitespace is ignored.
+    pub fn parse(text: &[u8]) -> Option<Self> {
+        fn hex(byte: u8) -> Option<u8> {
+            match byte {
+                b'0'..=b'9' => Some(byte - b'0'),
+                b'a'..=b'f' => Some(byte - b'a' + 10),
+                b'A'..=b'F' => Some(byte - b'A' + 10),
+                _ => None,
+            }
+        }
+
+        let text = core::str::from_utf8(text).ok()?.trim().as_bytes();
+        let is_uuid = match text.len() {
+            32 => false,
+            36 => true,
+            _ => return None,
+        };
+
+        let mut bytes = [0u8; 16];
+        let mut digits = text.iter().copied().enumerate().filter(|(idx, byte)| {
+            !(is_uuid && matches!(idx, 8 | 13 | 18 | 23) && *byte == b'-')
+        });
+        for byte in bytes.iter_mut() {
+            let high = hex(digits.next()?.1)?;
+            let low = hex(digits.next()?.1)?;
+            *byte = (high << 4) | low;
+        }
+
+        match digits.next() {
+            Some(_) => None,
+            None => Some(Self::new(bytes)),
+        }
+    }
+
+    ///Parses identifier in format of `machine-id(5)`.
+    ///
+    ///Unlike `Id128::parse`, accepts only 32 lower-case hexadecimal characters, optionally followed by single newline.
+    pub fn parse_machine_id(text: &[u8]) -> Option<Self> {
+        let text = text.strip_suffix(b"\n").unwrap_or(text);
+        match text.len() == 32 && text.iter().all(|byte| matches!(byte, b'0'..=b'9' | b'a'..=b'f')) {
+            true => Self::parse(text),
+            false => None,
+        }
+    }
+
+    ///Derives application specific identifier, without exposing this one.
+    ///
+    ///Uses the same algorithm as systemd's `sd_id128_get_machine_app_specific`:
+    ///HMAC-SHA-256 of `app_id`, keyed with this identifier, truncated and formatted as UUID v4.
+    pub fn app_specific(&self, app_id: &Id128) -> Id128 {
+        let digest = super::sha256::hmac(&self.bytes, &app_id.bytes);
+
+        let mut bytes = [0u8; 16];
+        bytes.copy_from_slice(&digest[..16]);
+        //UUID v4, variant 1
+        bytes[6] = (bytes[6] & 0x0F) | 0x40;
+        bytes[8] = (bytes[8] & 0x3F) | 0x80;
+        Self::new(bytes)
+    }
+}
+
+impl fmt::Display for Id128 {
+    fn fmt(&self, fmt: &mut fmt::Formatter) -> fmt::Result {
+        for byte in self.bytes.iter() {
+            fmt.write_fmt(format_args!("{:02x}", byte))?;
+        }
+        Ok(())
+    }
+}
+
+impl fmt::Debug for Id128 {
+    #[inline(always)]
+    fn fmt(&self, fmt: &mut fmt::Formatter) -> fmt::Result {
+        fmt::Display::fmt(self, fmt)
+    }
+}
@@ -1,3 +1,7 @@
 pub mod mem;
+pub mod cpu;
+pub mod cpu_info;
+pub mod numa;
 pub mod network;
 pub mod host;
+pub(crate) mod sha256;
@@ -1,10 +1,12 @@
 #[cfg(feature = "std")]
 extern crate std;
+extern crate alloc;
 
+use alloc::vec::Vec;
 use core::fmt;
 
 ///IP address
-#[derive(Debug, Clone, Copy)]
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
 pub enum Ip {
     ///IP version 4
     V4([u8; 4]),
@@ -81,7 +83,7 @@ impl fmt::Display for Ip {
     }
 }
 
-#[derive(Debug, Clone, Copy)]
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
 ///Socket's address.
 pub struct Address {
     ///Ip address.
@@ -134,3 +136,90 @@ impl Address {
         }
     }
 }
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+///Difference between two snapshots of network interfaces.
+///
+///Produced by `Interfaces::diff`, where `I` is platform's interface type.
+pub enum Change<I> {
+    ///Interface is present only in new snapshot.
+    InterfaceAdded(I),
+    ///Interface is present only in old snapshot.
+    InterfaceRemoved(I),
+    ///Address is assigned to the interface in new snapshot.
+    AddressAdded(I, Address),
+    ///Address is no longer assigned to the interface.
+    AddressRemoved(I, Address),
+    ///Address is kept, but its prefix changed.
+    ///
+    ///Contains interface from new snapshot, old address and new address.
+    PrefixChanged(I, Address, Address),
+}
+
+///Compares addresses of the same interface, pushing changes into `out`.
+pub(crate) fn diff_addresses<I: Copy, O: Iterator<Item = Address> + Clone, N: Iterator<Item = Address> + Clone>(old_interface: I, old: O, new_interface: I, new: N, out: &mut Vec<Change<I>>) {
+    for old_addr in old.clone() {
+        match new.clone().find(|new_addr| new_addr.ip == old_addr.ip) {
+            Some(new_addr) => if new_addr.prefix != old_addr.prefix {
+                out.push(Change::PrefixChanged(new_interface, old_addr, new_addr));
+            },
+            None => out.push(Change::AddressRemoved(old_interface, old_addr)),
+        }
+    }
+
+    for new_addr in new {
+        if !old.clone().any(|old_addr| old_addr.ip == new_addr.ip) {
+            out.push(Change::AddressAdded(new_interface, new_addr));
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    extern crate alloc;
+
+    use alloc::vec::Vec;
+
+    use super::{diff_addresses, Address, Change, Ip};
+
+    const fn v4(ip: [u8; 4], prefix: u8) -> Address {
+        Address {
+            ip: Ip::V4(ip),
+            prefix,
+        }
+    }
+
+    #[test]
+    fn should_diff_addresses() {
+        let kept = v4([10, 0, 0, 1], 24);
+        let removed = v4([10, 0, 0, 2], 24);
+        let old_prefix = v4([192, 168, 1, 1], 24);
+        let new_prefix = v4([192, 168, 1, 1], 16);
+        let added = Address {
+            ip: Ip::V6([0xfe80, 0, 0, 0, 0, 0, 0, 1]),
+            prefix: 64,
+        };
+
+        let old = [kept, removed, old_prefix];
+        let new = [new_prefix, kept, added];
+        let mut changes = Vec::new();
+        diff_addresses("old", old.iter().copied(), "new", new.iter().copied(), &mut changes);
+
+        assert_eq!(changes, [
+            Change::AddressRemoved("old", removed),
+            Change::PrefixChanged("new", old_prefix, new_prefix),
+            Change::AddressAdded("new", added),
+        ]);
+
+        changes.clear();
+        diff_addresses("old", old.iter().copied(), "new", old.iter().copied(), &mut changes);
+        assert!(changes.is_empty());
+
+        diff_addresses("old", [].iter().copied(), "new", [kept].iter().copied(), &mut changes);
+        diff_addresses("old", [kept].iter().copied(), "new", [].iter().copied(), &mut changes);
+        assert_eq!(changes, [
+            Change::AddressAdded("new", kept),
+            Change::AddressRemoved("old", kept),
+        ]);
+    }
+}
@@ -0,0 +1,58 @@
+//! NUMA data types.
+
+extern crate alloc;
+
+use alloc::vec::Vec;
+
+use super::cpu::CpuSet;
+use crate::mem::SystemMemory;
+
+///Distance of node to itself, as defined by ACPI SLIT.
+pub const LOCAL_DISTANCE: u32 = 10;
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+///NUMA node.
+pub struct Node {
+    ///Node's id.
+    pub id: usize,
+    ///CPUs, belonging to the node.
+    pub cpus: CpuSet,
+    ///Total memory of the node in bytes.
+    pub total_memory: u64,
+    ///Free memory of the node in bytes.
+    ///
+    ///For single node, returned on systems without NUMA, it is memory available to allocate, as reported by `SystemMemory::avail`.
+    pub free_memory: u64,
+    ///Relative distance to each node, identified by its id.
+    ///
+    ///Distance to itself is `LOCAL_DISTANCE` and greater values mean slower access.
+    pub distances: Vec<(usize, u32)>,
+}
+
+impl Node {
+    ///Creates node, covering specified CPUs and whole system memory, for machines without NUMA.
+    pub(crate) fn single(cpus: CpuSet) -> Self {
+        let memory = SystemMemory::new();
+        Self {
+            id: 0,
+            cpus,
+            total_memory: memory.total,
+            free_memory: memory.avail,
+            distances: alloc::vec![(0, LOCAL_DISTANCE)],
+        }
+    }
+
+    #[inline]
+    ///Returns distance to node with specified id, if it is known.
+    pub fn distance(&self, node: usize) -> Option<u32> {
+        self.distances.iter().find(|(id, _)| *id == node).map(|(_, distance)| *distance)
+    }
+}
+
+#[cfg(not(any(target_os = "linux", target_os = "android")))]
+///Returns NUMA nodes of the system.
+///
+///NUMA topology is not supported on this platform, hence single node, covering all CPUs and memory, is returned.
+pub fn nodes() -> Vec<Node> {
+    alloc::vec![Node::single((0..crate::cpu::count()).collect())]
+}
@@ -0,0 +1,40 @@
+//! CPU information.
+
+pub use crate::unix::posix::cpu::*;
+
+use core::{mem, ptr};
+
+fn sysctl_count(name: &[u8]) -> Option<usize> {
+    let mut value: libc::c_int = 0;
+    let mut value_len = mem::size_of_val(&value) as libc::size_t;
+    let result = unsafe {
+        libc::sysctlbyname(name.as_ptr() as _, &mut value as *mut _ as _, &mut value_len, ptr::null_mut(), 0)
+    };
+
+    match result == 0 && value > 0 {
+        true => Some(value as usize),
+        false => None,
+    }
+}
+
+///Returns number of logical CPUs (hardware threads) on system (`hw.logicalcpu_max`).
+pub fn logical_count() -> usize {
+    sysctl_count(b"hw.logicalcpu_max\0").unwrap_or_else(configured_count)
+}
+
+///Returns number of CPUs, current process is allowed to run on.
+///
+///Affinity is not available on this platform, hence it is the same as `online_count()`.
+pub fn available_count() -> usize {
+    online_count()
+}
+
+///Returns number of physical CPU cores on system (`hw.physicalcpu_max`).
+pub fn physical_core_count() -> usize {
+    sysctl_count(b"hw.physicalcpu_max\0").unwrap_or_else(logical_count)
+}
+
+///Returns number of CPU sockets (physical packages) on system (`hw.packages`).
+pub fn socket_count() -> usize {
+    sysctl_count(b"hw.packages\0").unwrap_or(1)
+}
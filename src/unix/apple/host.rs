@@ -0,0 +1,32 @@
+//! Host information.
+
+pub use crate::unix::posix::host::*;
+use super::get_sysctl;
+
+use core::mem;
+use core::time::Duration;
+
+///Returns time elapsed since boot, including time spent in sleep.
+pub fn uptime() -> Option<Duration> {
+    let boot_time = boot_time()?;
+    let mut now = mem::MaybeUninit::<libc::timespec>::uninit();
+    let now = unsafe {
+        if libc::clock_gettime(libc::CLOCK_REALTIME, now.as_mut_ptr()) != 0 {
+            return None;
+        }
+        now.assume_init()
+    };
+
+    Duration::new(now.tv_sec as u64, now.tv_nsec as u32).checked_sub(boot_time)
+}
+
+///Returns boot time as duration since Unix epoch.
+///
+///Uses `kern.boottime` sysctl.
+pub fn boot_time() -> Option<Duration> {
+    let time: libc::timeval = get_sysctl([libc::CTL_KERN, libc::KERN_BOOTTIME]);
+    match time.tv_sec {
+        0 => None,
+        secs => Some(Duration::new(secs as u64, time.tv_usec as u32 * 1_000)),
+    }
+}
@@ -1,5 +1,7 @@
 pub use super::posix::network;
-pub use super::posix::cpu;
+pub use super::posix::numa;
+pub mod cpu;
+pub mod host;
 pub mod mem;
 
 use core::ptr;
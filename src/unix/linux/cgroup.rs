@@ -0,0 +1,169 @@
+//! Control group resolution via `/proc/self/cgroup` and `/proc/self/mountinfo`.
+
+extern crate alloc;
+
+use alloc::vec::Vec;
+
+use crate::unix::linux::fs;
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub(crate) enum Version {
+    V1,
+    V2,
+}
+
+///Control group of current process.
+pub(crate) struct Cgroup {
+    pub(crate) version: Version,
+    ///Directory of control group, prefixed with root.
+    dir: Vec<u8>,
+    ///Length of hierarchy's mount point within `dir`.
+    mount_len: usize,
+}
+
+///Unescapes octal sequences (e.g. `\040`), used by `mountinfo` for special characters.
+fn unescape(value: &[u8]) -> Vec<u8> {
+    let mut result = Vec::with_capacity(value.len());
+    let mut idx = 0;
+    while idx < value.len() {
+        match value.get(idx..idx + 4) {
+            Some([b'\\', a, b, c]) if [a, b, c].iter().all(|digit| (b'0'..=b'7').contains(*digit)) => {
+                result.push(((a - b'0') << 6) | ((b - b'0') << 3) | (c - b'0'));
+                idx += 4;
+            },
+            _ => {
+                result.push(value[idx]);
+                idx += 1;
+            }
+        }
+    }
+    result
+}
+
+///Looks up mount of the hierarchy, returning its root and mount point.
+fn find_mount(mountinfo: &[u8], version: Version, controller: &[u8]) -> Option<(Vec<u8>, Vec<u8>)> {
+    for line in mountinfo.split(|byte| *byte == b'\n') {
+        //Optional fields are terminated by single hyphen
+        let separator = match line.windows(3).position(|window| window == b" - ") {
+            Some(separator) => separator,
+            None => continue,
+        };
+        let mut fields = line[..separator].split(|byte| *byte == b' ');
+        let mut super_fields = line[separator + 3..].split(|byte| *byte == b' ');
+
+        let (root, mount_point) = match (fields.nth(3), fields.next()) {
+            (Some(root), Some(mount_point)) => (root, mount_point),
+            _ => continue,
+        };
+        let fs_type = super_fields.next();
+        let options = super_fields.nth(1).unwrap_or(&[]);
+
+        let is_match = match version {
+            Version::V1 => fs_type == Some(b"cgroup") && options.split(|byte| *byte == b',').any(|option| option == controller),
+            Version::V2 => fs_type == Some(b"cgroup2"),
+        };
+
+        if is_match {
+            return Some((unescape(root), unescape(mount_point)));
+        }
+    }
+
+    None
+}
+
+impl Cgroup {
+    ///Finds control group of current process within filesystem, mounted at `root`.
+    ///
+    ///Hierarchy of cgroup v1, having `controller` attached, takes priority over unified hierarchy.
+    pub(crate) fn find(root: &[u8], controller: &[u8]) -> Option<Self> {
+        let cgroup = fs::read(&[root, b"/proc/self/cgroup"])?;
+        let mountinfo = fs::read(&[root, b"/proc/self/mountinfo"])?;
+
+        let mut v1 = None;
+        let mut v2 = None;
+        for line in cgroup.split(|byte| *byte == b'\n') {
+            let mut parts = line.splitn(3, |byte| *byte == b':');
+            let (id, controllers, path) = match (parts.next(), parts.next(), parts.next()) {
+                (Some(id), Some(controllers), Some(path)) => (id, controllers, path),
+                _ => continue,
+            };
+
+            if id == b"0" && controllers.is_empty() {
+                v2 = Some(path);
+            } else if controllers.split(|byte| *byte == b',').any(|name| name == controller) {
+                v1 = Some(path);
+            }
+        }
+
+        let (version, path, (mount_root, mount_point)) = match v1 {
+            Some(path) => match find_mount(&mountinfo, Version::V1, controller) {
+                Some(mount) => (Version::V1, path, mount),
+                None => return None,
+            },
+            None => (Version::V2, v2?, find_mount(&mountinfo, Version::V2, controller)?),
+        };
+
+        //Path is relative to the root of hierarchy, which can be different from mount's root within container
+        let path = match path.strip_prefix(mount_root.as_slice()) {
+            _ if mount_root == b"/" => path,
+            Some(rest) if rest.is_empty() || rest[0] == b'/' => rest,
+            //Container sees only its own cgroup mounted, but without cgroup namespace path is host's one
+            _ => &[],
+        };
+        let path = match path {
+            b"/" => &[],
+            path => path,
+        };
+
+        let mut dir = Vec::with_capacity(root.len() + mount_point.len() + path.len());
+        dir.extend_from_slice(root);
+        dir.extend_from_slice(&mount_point);
+        let mount_len = dir.len();
+        dir.extend_from_slice(path);
+
+        Some(Self {
+            version,
+            dir,
+            mount_len,
+        })
+    }
+
+    #[inline]
+    ///Finds control group of current process within unified hierarchy, mounted at `root`.
+    pub(crate) fn unified(root: &[u8]) -> Option<Self> {
+        //No cgroup v1 hierarchy has empty controller attached
+        Self::find(root, b"")
+    }
+
+    #[inline(always)]
+    ///Returns directory of control group, prefixed with root.
+    pub(crate) fn dir(&self) -> &[u8] {
+        &self.dir
+    }
+
+    ///Returns iterator over directories of control group and its ancestors, up to hierarchy's root.
+    pub(crate) fn ancestors(&self) -> Ancestors<'_> {
+        Ancestors {
+            dir: Some(&self.dir),
+            mount_len: self.mount_len,
+        }
+    }
+}
+
+pub(crate) struct Ancestors<'a> {
+    dir: Option<&'a [u8]>,
+    mount_len: usize,
+}
+
+impl<'a> Iterator for Ancestors<'a> {
+    type Item = &'a [u8];
+
+    fn next(&mut self) -> Option<Self::Item> {
+        let dir = self.dir?;
+        self.dir = match dir.iter().rposition(|byte| *byte == b'/') {
+            Some(idx) if idx >= self.mount_len => Some(&dir[..idx]),
+            _ => None,
+        };
+        Some(dir)
+    }
+}
@@ -1,33 +1,243 @@
 //! CPU information.
 
-use core::mem;
-
-fn count_with_affinity() -> usize {
-    let mut count = 0;
-    let mut set = mem::MaybeUninit::<libc::cpu_set_t>::uninit();
-    let result = unsafe {
-        libc::sched_getaffinity(0, mem::size_of_val(&set), set.as_mut_ptr())
-    };
-
-    if result == 0 {
-        let set = unsafe {
-            set.assume_init()
+extern crate alloc;
+
+use alloc::vec::Vec;
+
+use core::{cmp, mem};
+
+use crate::unix::linux::fs;
+use crate::unix::linux::cgroup::{Cgroup, Version};
+
+pub use crate::unix::posix::cpu::configured_count;
+pub use crate::data::cpu::{CpuSet, CpuSetIter};
+
+mod times;
+pub use times::{Times, Stats};
+mod usage;
+pub use usage::{Usage, CpuUsage, UsageSampler};
+mod info;
+pub use info::{info, info_all, has_feature, Info, Feature};
+mod cache;
+pub use cache::{caches, caches_with_root, Cache, CacheType};
+mod freq;
+pub use freq::{frequency, frequency_with_root, Frequency};
+
+const CPU_DIR: &[u8] = b"/sys/devices/system/cpu/";
+
+///Retrieves set of CPUs, thread or process with `pid` is allowed to run on.
+///
+///When `pid` is 0, affinity of the calling thread is returned.
+///
+///In case of failure please check `std::io::Error::last_os_error()`
+pub fn affinity(pid: u32) -> Option<CpuSet> {
+    const WORD_SIZE: usize = mem::size_of::<usize>();
+
+    //Kernel rejects mask, smaller than number of CPUs it supports
+    let mut len = libc::CPU_SETSIZE as usize / 8 / WORD_SIZE;
+    loop {
+        let mut words = alloc::vec![0usize; len];
+        let result = unsafe {
+            libc::sched_getaffinity(pid as _, len * WORD_SIZE, words.as_mut_ptr() as _)
         };
 
-        for idx in 0..libc::CPU_SETSIZE as usize {
-            if unsafe { libc::CPU_ISSET(idx, &set) } {
-                count += 1
-            }
+        if result == 0 {
+            return Some(CpuSet::from_words(words));
+        } else if fs::errno() != libc::EINVAL || len * WORD_SIZE * 8 >= CpuSet::MAX_CPUS {
+            return None;
         }
+
+        len *= 2;
+    }
+}
+
+///Sets CPUs, thread or process with `pid` is allowed to run on.
+///
+///When `pid` is 0, affinity of the calling thread is set.
+///
+///In case of failure please check `std::io::Error::last_os_error()`
+pub fn set_affinity(pid: u32, cpus: &CpuSet) -> bool {
+    let words = cpus.words();
+    unsafe {
+        libc::sched_setaffinity(pid as _, mem::size_of_val(words), words.as_ptr() as _) == 0
     }
+}
 
-    count
+fn read_cpu_list(name: &[u8]) -> Option<CpuSet> {
+    let content = fs::read(&[CPU_DIR, name])?;
+    CpuSet::parse(core::str::from_utf8(&content).ok()?)
+}
+
+fn list_count(name: &[u8]) -> Option<usize> {
+    match read_cpu_list(name)?.len() {
+        0 => None,
+        count => Some(count),
+    }
+}
+
+struct Topology {
+    cores: usize,
+    sockets: usize,
+}
+
+fn topology() -> Option<Topology> {
+    //(package, die, core) uniquely identifies physical core
+    let mut cores = Vec::new();
+    let mut buffer = [0u8; fs::NUMBER_SIZE];
+    for cpu in read_cpu_list(b"online")?.iter() {
+        let cpu = fs::format_number(cpu, &mut buffer);
+        let package = fs::read_value::<i32>(&[CPU_DIR, b"cpu", cpu, b"/topology/physical_package_id"]);
+        let core = fs::read_value::<i32>(&[CPU_DIR, b"cpu", cpu, b"/topology/core_id"]);
+        let die = fs::read_value::<i32>(&[CPU_DIR, b"cpu", cpu, b"/topology/die_id"]).unwrap_or(0);
+
+        if let (Some(package), Some(core)) = (package, core) {
+            cores.push((package, die, core));
+        }
+    }
+
+    if cores.is_empty() {
+        return None;
+    }
+
+    cores.sort_unstable();
+    cores.dedup();
+    let core_count = cores.len();
+
+    cores.dedup_by_key(|(package, _, _)| *package);
+    Some(Topology {
+        cores: core_count,
+        sockets: cores.len(),
+    })
 }
 
 ///Returns number of CPU cores on system, as reported by OS.
 pub fn count() -> usize {
-    match count_with_affinity() {
-        0 => crate::unix::posix::cpu::count(),
-        count => count,
+    match affinity(0).map(|cpus| cpus.len()) {
+        Some(0) | None => crate::unix::posix::cpu::count(),
+        Some(count) => count,
+    }
+}
+
+///Returns number of logical CPUs (hardware threads), present on system.
+///
+///Uses `/sys/devices/system/cpu/present`, falling back to `configured_count()`.
+pub fn logical_count() -> usize {
+    list_count(b"present").unwrap_or_else(configured_count)
+}
+
+///Returns number of CPUs, currently online.
+///
+///Uses `/sys/devices/system/cpu/online`, falling back to `_SC_NPROCESSORS_ONLN`.
+pub fn online_count() -> usize {
+    list_count(b"online").unwrap_or_else(crate::unix::posix::cpu::online_count)
+}
+
+///Returns number of CPUs, current process is allowed to run on (`sched_getaffinity`).
+///
+///Falls back to `online_count()`, if affinity cannot be retrieved.
+pub fn available_count() -> usize {
+    match affinity(0).map(|cpus| cpus.len()) {
+        Some(0) | None => online_count(),
+        Some(count) => count,
+    }
+}
+
+///Returns number of physical CPU cores, as per topology of online CPUs.
+///
+///Falls back to `logical_count()`, if topology is not available.
+pub fn physical_core_count() -> usize {
+    match topology() {
+        Some(topology) => topology.cores,
+        None => logical_count(),
+    }
+}
+
+///Returns number of CPU sockets (physical packages), as per topology of online CPUs.
+///
+///Falls back to 1, if topology is not available.
+pub fn socket_count() -> usize {
+    match topology() {
+        Some(topology) => topology.sockets,
+        None => 1,
+    }
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+///CPU bandwidth limit of control group.
+pub struct CpuQuota {
+    ///CPU time in microseconds, that control group can use within single period.
+    pub quota: u64,
+    ///Length of period in microseconds.
+    pub period: u64,
+}
+
+impl CpuQuota {
+    fn read(dir: &[u8], version: Version) -> Option<Self> {
+        let (quota, period) = match version {
+            Version::V2 => {
+                let content = fs::read(&[dir, b"/cpu.max"])?;
+                let content = core::str::from_utf8(&content).ok()?;
+                let mut parts = content.split_whitespace();
+                (parts.next()?.parse::<i64>().ok()?, parts.next()?.parse().ok()?)
+            },
+            Version::V1 => (fs::read_value(&[dir, b"/cpu.cfs_quota_us"])?, fs::read_value(&[dir, b"/cpu.cfs_period_us"])?),
+        };
+
+        //Unlimited quota is `max` in v2 and -1 in v1
+        match quota > 0 && period > 0 {
+            true => Some(Self {
+                quota: quota as u64,
+                period,
+            }),
+            false => None,
+        }
+    }
+
+    ///Reads CPU quota of current process's control group, using filesystem mounted at `root`.
+    ///
+    ///Both cgroup v1 (`cpu.cfs_quota_us` and `cpu.cfs_period_us`) and v2 (`cpu.max`) are supported.
+    ///As limits of ancestors apply to descendants, the most restrictive one is returned.
+    ///
+    ///Returns `None` if control group has no CPU limit.
+    pub fn with_root(root: &str) -> Option<Self> {
+        let cgroup = Cgroup::find(root.as_bytes(), b"cpu")?;
+
+        let mut result: Option<Self> = None;
+        for dir in cgroup.ancestors() {
+            if let Some(quota) = Self::read(dir, cgroup.version) {
+                result = match result {
+                    Some(result) if result.quota as u128 * quota.period as u128 <= quota.quota as u128 * result.period as u128 => Some(result),
+                    _ => Some(quota),
+                };
+            }
+        }
+
+        result
+    }
+
+    #[inline(always)]
+    ///Reads CPU quota of current process's control group.
+    ///
+    ///Returns `None` if control group has no CPU limit.
+    pub fn get() -> Option<Self> {
+        Self::with_root("")
+    }
+
+    #[inline]
+    ///Returns number of CPUs, quota corresponds to, rounded up.
+    pub fn cpus(&self) -> usize {
+        let cpus = self.quota.div_ceil(self.period);
+        cmp::max(cpus, 1) as usize
+    }
+}
+
+///Returns number of CPUs, current process can effectively utilize.
+///
+///It is minimum of `available_count()` and CPU quota of the control group, if any.
+pub fn effective_parallelism() -> usize {
+    let available = available_count();
+    match CpuQuota::get() {
+        Some(quota) => cmp::min(available, quota.cpus()),
+        None => available,
     }
 }
@@ -0,0 +1,273 @@
+//! CPU cache hierarchy.
+
+extern crate alloc;
+
+use alloc::string::String;
+use alloc::vec::Vec;
+
+use super::{CpuSet, CPU_DIR};
+use crate::unix::linux::fs;
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord)]
+///Type of cache.
+pub enum CacheType {
+    ///Data cache.
+    Data,
+    ///Instruction cache.
+    Instruction,
+    ///Cache, holding both data and instructions.
+    Unified,
+}
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+///CPU cache.
+pub struct Cache {
+    ///Cache level, starting from 1.
+    pub level: u8,
+    ///Cache type.
+    pub kind: CacheType,
+    ///Size in bytes.
+    pub size: u64,
+    ///Line size in bytes.
+    pub line_size: u32,
+    ///Number of ways, `None` if cache is fully associative or it is unknown.
+    pub associativity: Option<u32>,
+    ///CPUs sharing this cache.
+    ///
+    ///For last level cache it denotes cache domain (e.g. AMD's CCX), useful for thread placement.
+    pub cpus: CpuSet,
+}
+
+fn parse_size(value: &str) -> Option<u64> {
+    let (value, multiplier) = match value.as_bytes().last()? {
+        b'K' => (&value[..value.len() - 1], 1024),
+        b'M' => (&value[..value.len() - 1], 1024 * 1024),
+        b'G' => (&value[..value.len() - 1], 1024 * 1024 * 1024),
+        _ => (value, 1),
+    };
+
+    value.parse::<u64>().ok()?.checked_mul(multiplier)
+}
+
+struct CacheDir<'a> {
+    root: &'a [u8],
+    cpu: &'a [u8],
+    index: &'a [u8],
+}
+
+impl<'a> CacheDir<'a> {
+    #[inline]
+    fn exists(&self) -> bool {
+        fs::exists(&[self.root, CPU_DIR, b"cpu", self.cpu, b"/cache/index", self.index])
+    }
+
+    fn read(&self, name: &[u8]) -> Option<String> {
+        let content = fs::read(&[self.root, CPU_DIR, b"cpu", self.cpu, b"/cache/index", self.index, name])?;
+        Some(core::str::from_utf8(&content).ok()?.trim().into())
+    }
+
+    fn cache(&self) -> Option<Cache> {
+        let kind = match self.read(b"/type")?.as_str() {
+            "Data" => CacheType::Data,
+            "Instruction" => CacheType::Instruction,
+            "Unified" => CacheType::Unified,
+            _ => return None,
+        };
+
+        Some(Cache {
+            level: self.read(b"/level")?.parse().ok()?,
+            kind,
+            size: parse_size(&self.read(b"/size")?)?,
+            line_size: self.read(b"/coherency_line_size").and_then(|size| size.parse().ok()).unwrap_or(0),
+            associativity: self.read(b"/ways_of_associativity").and_then(|ways| ways.parse().ok()).filter(|ways| *ways != 0),
+            cpus: CpuSet::parse(&self.read(b"/shared_cpu_list")?)?,
+        })
+    }
+}
+
+fn sort(caches: &mut [Cache]) {
+    caches.sort_unstable_by_key(|cache| (cache.level, cache.kind, cache.cpus.iter().next()));
+}
+
+///Reads cache hierarchy of online CPUs from `sysfs`, mounted at `root`.
+///
+///Each cache is listed once, together with CPUs sharing it.
+///
+///Returns `None` if cache information is not available.
+pub fn caches_with_root(root: &str) -> Option<Vec<Cache>> {
+    let root = root.as_bytes();
+    let online = fs::read(&[root, CPU_DIR, b"online"])?;
+    let online = CpuSet::parse(core::str::from_utf8(&online).ok()?)?;
+
+    let mut result = Vec::<Cache>::new();
+    let mut cpu_buffer = [0u8; fs::NUMBER_SIZE];
+    let mut index_buffer = [0u8; fs::NUMBER_SIZE];
+    for cpu in online.iter() {
+        let cpu = fs::format_number(cpu, &mut cpu_buffer);
+        for index in 0.. {
+            let dir = CacheDir {
+                root,
+                cpu,
+                index: fs::format_number(index, &mut index_buffer),
+            };
+            if !dir.exists() {
+                break;
+            }
+
+            let cache = match dir.cache() {
+                Some(cache) => cache,
+                None => continue,
+            };
+            if !result.iter().any(|known| known.level == cache.level && known.kind == cache.kind && known.cpus == cache.cpus) {
+                result.push(cache);
+            }
+        }
+    }
+
+    if result.is_empty() {
+        return None;
+    }
+
+    sort(&mut result);
+    Some(result)
+}
+
+#[cfg(any(target_arch = "x86", target_arch = "x86_64"))]
+mod x86 {
+    extern crate alloc;
+
+    use alloc::vec::Vec;
+
+    use super::{Cache, CacheType, CpuSet};
+    use crate::data::cpu_info::x86::{cpuid, cpuid_count, EXT_LEAF};
+    use crate::unix::linux::cpu::info::current_cpu;
+    use crate::unix::linux::fs;
+
+    const INTEL_LEAF: u32 = 4;
+    const AMD_LEAF: u32 = 0x8000_001D;
+    //Vendor's signature's first register value
+    const AMD_EBX: u32 = u32::from_le_bytes(*b"Auth");
+    const HYGON_EBX: u32 = u32::from_le_bytes(*b"Hygo");
+    const TOPOEXT_BIT: u32 = 1 << 22;
+    const FULLY_ASSOCIATIVE_BIT: u32 = 1 << 9;
+
+    ///Reads APIC id of each CPU from `/proc/cpuinfo`.
+    fn apic_ids() -> Vec<(usize, u32)> {
+        let mut result = Vec::new();
+        let content = match fs::read(&[b"/proc/cpuinfo"]) {
+            Some(content) => content,
+            None => return result,
+        };
+        let content = match core::str::from_utf8(&content) {
+            Ok(content) => content,
+            Err(_) => return result,
+        };
+
+        let mut cpu = None;
+        for line in content.lines() {
+            match line.split_once(':') {
+                Some((key, value)) if key.trim() == "processor" => cpu = value.trim().parse().ok(),
+                Some((key, value)) if key.trim() == "apicid" => if let (Some(cpu), Ok(id)) = (cpu, value.trim().parse()) {
+                    result.push((cpu, id));
+                },
+                _ => (),
+            }
+        }
+
+        result
+    }
+
+    ///Enumerates caches using deterministic cache parameters leaf of CPUID.
+    ///
+    ///CPUs sharing cache are determined by APIC id, in the same way as kernel does.
+    pub(super) fn caches() -> Option<Vec<Cache>> {
+        let vendor = cpuid(0);
+        let max_ext_leaf = cpuid(EXT_LEAF).eax;
+        let is_amd = vendor.ebx == AMD_EBX || vendor.ebx == HYGON_EBX;
+
+        let leaf = if is_amd && max_ext_leaf >= AMD_LEAF && cpuid(EXT_LEAF + 1).ecx & TOPOEXT_BIT != 0 {
+            AMD_LEAF
+        } else if !is_amd && vendor.eax >= INTEL_LEAF {
+            INTEL_LEAF
+        } else {
+            return None;
+        };
+
+        let apic_ids = apic_ids();
+        let mut result = Vec::new();
+        for subleaf in 0.. {
+            let info = cpuid_count(leaf, subleaf);
+            let kind = match info.eax & 0x1f {
+                0 => break,
+                1 => CacheType::Data,
+                2 => CacheType::Instruction,
+                3 => CacheType::Unified,
+                _ => continue,
+            };
+
+            let line_size = (info.ebx & 0xfff) + 1;
+            let partitions = ((info.ebx >> 12) & 0x3ff) + 1;
+            let ways = ((info.ebx >> 22) & 0x3ff) + 1;
+            let sets = info.ecx as u64 + 1;
+            let sharing = ((info.eax >> 14) & 0xfff) + 1;
+
+            let template = Cache {
+                level: ((info.eax >> 5) & 0x7) as u8,
+                kind,
+                size: ways as u64 * partitions as u64 * line_size as u64 * sets,
+                line_size,
+                associativity: match info.eax & FULLY_ASSOCIATIVE_BIT {
+                    0 => Some(ways),
+                    _ => None,
+                },
+                cpus: CpuSet::new(),
+            };
+
+            if apic_ids.is_empty() {
+                let mut cache = template;
+                cache.cpus.insert(current_cpu());
+                result.push(cache);
+                continue;
+            }
+
+            //Cache is shared by CPUs, which APIC ids differ only in lowest bits
+            let shift = sharing.next_power_of_two().trailing_zeros();
+            let mut domains = Vec::<(u32, CpuSet)>::new();
+            for (cpu, id) in apic_ids.iter() {
+                match domains.iter_mut().find(|(domain, _)| *domain == id >> shift) {
+                    Some((_, cpus)) => {
+                        cpus.insert(*cpu);
+                    },
+                    None => domains.push((id >> shift, core::iter::once(*cpu).collect())),
+                }
+            }
+
+            for (_, cpus) in domains {
+                let mut cache = template.clone();
+                cache.cpus = cpus;
+                result.push(cache);
+            }
+        }
+
+        match result.is_empty() {
+            true => None,
+            false => Some(result),
+        }
+    }
+}
+
+///Returns cache hierarchy of online CPUs.
+///
+///Uses `/sys/devices/system/cpu/cpu*/cache`, falling back to CPUID on x86.
+///Each cache is listed once, together with CPUs sharing it, ordered by level.
+///
+///Returns `None` if cache information is not available.
+pub fn caches() -> Option<Vec<Cache>> {
+    let result = caches_with_root("");
+    #[cfg(any(target_arch = "x86", target_arch = "x86_64"))]
+    let result = result.or_else(|| x86::caches().map(|mut caches| {
+        sort(&mut caches);
+        caches
+    }));
+    result
+}
@@ -0,0 +1,147 @@
+//! CPU frequency scaling, read from `cpufreq`.
+
+extern crate alloc;
+
+use alloc::string::String;
+use alloc::vec::Vec;
+
+use super::CPU_DIR;
+use crate::unix::linux::fs;
+
+#[derive(Debug, Clone, Default, PartialEq, Eq)]
+///CPU frequency information.
+///
+///All frequencies are in kHz.
+///
+///When `cpufreq` is not available, only `current` frequency is known out of `/proc/cpuinfo`.
+pub struct Frequency {
+    ///Current frequency.
+    pub current: Option<u64>,
+    ///Minimum frequency, allowed by scaling policy.
+    pub min: Option<u64>,
+    ///Maximum frequency, allowed by scaling policy.
+    pub max: Option<u64>,
+    ///Scaling governor (e.g. `performance`, `powersave`, `schedutil`).
+    pub governor: Option<String>,
+    ///Scaling driver (e.g. `intel_pstate`, `acpi-cpufreq`).
+    pub driver: Option<String>,
+    ///Governors, that can be set.
+    pub available_governors: Vec<String>,
+    ///Frequencies, that can be set.
+    ///
+    ///Empty when driver selects frequency on its own.
+    pub available_frequencies: Vec<u64>,
+}
+
+impl Frequency {
+    #[inline]
+    ///Returns whether frequency is controlled by `cpufreq`.
+    pub fn is_scaled(&self) -> bool {
+        self.driver.is_some() || self.governor.is_some()
+    }
+}
+
+struct FreqDir<'a> {
+    root: &'a [u8],
+    cpu: &'a [u8],
+}
+
+impl<'a> FreqDir<'a> {
+    #[inline]
+    fn exists(&self) -> bool {
+        fs::exists(&[self.root, CPU_DIR, b"cpu", self.cpu, b"/cpufreq"])
+    }
+
+    fn read(&self, name: &[u8]) -> Option<String> {
+        let content = fs::read(&[self.root, CPU_DIR, b"cpu", self.cpu, b"/cpufreq/", name])?;
+        let content = core::str::from_utf8(&content).ok()?.trim();
+        match content.is_empty() {
+            true => None,
+            false => Some(content.into()),
+        }
+    }
+
+    #[inline]
+    fn read_khz(&self, name: &[u8]) -> Option<u64> {
+        self.read(name)?.parse().ok()
+    }
+
+    fn read_list(&self, name: &[u8]) -> Vec<String> {
+        match self.read(name) {
+            Some(list) => list.split_whitespace().map(Into::into).collect(),
+            None => Vec::new(),
+        }
+    }
+}
+
+///Parses `cpu MHz` of `cpu` out of `/proc/cpuinfo`, returning it in kHz.
+fn cpuinfo_khz(content: &[u8], cpu: usize) -> Option<u64> {
+    let content = core::str::from_utf8(content).ok()?;
+
+    let mut is_cpu = false;
+    for line in content.lines() {
+        let (key, value) = match line.split_once(':') {
+            Some((key, value)) => (key.trim(), value.trim()),
+            None => continue,
+        };
+
+        match key {
+            "processor" => is_cpu = value.parse() == Ok(cpu),
+            "cpu MHz" if is_cpu => {
+                let mhz = value.parse::<f64>().ok()?;
+                return match mhz > 0.0 {
+                    true => Some((mhz * 1000.0) as u64),
+                    false => None,
+                };
+            },
+            _ => (),
+        }
+    }
+
+    None
+}
+
+///Reads frequency of `cpu` from `sysfs` and `procfs`, mounted at `root`.
+///
+///Returns `None` if frequency is unavailable, which is common for virtual machines.
+pub fn frequency_with_root(root: &str, cpu: usize) -> Option<Frequency> {
+    let root = root.as_bytes();
+    let mut buffer = [0u8; fs::NUMBER_SIZE];
+    let dir = FreqDir {
+        root,
+        cpu: fs::format_number(cpu, &mut buffer),
+    };
+
+    if dir.exists() {
+        let result = Frequency {
+            //cpuinfo_cur_freq is readable by root only
+            current: dir.read_khz(b"scaling_cur_freq").or_else(|| dir.read_khz(b"cpuinfo_cur_freq")),
+            min: dir.read_khz(b"scaling_min_freq").or_else(|| dir.read_khz(b"cpuinfo_min_freq")),
+            max: dir.read_khz(b"scaling_max_freq").or_else(|| dir.read_khz(b"cpuinfo_max_freq")),
+            governor: dir.read(b"scaling_governor"),
+            driver: dir.read(b"scaling_driver"),
+            available_governors: dir.read_list(b"scaling_available_governors"),
+            available_frequencies: dir.read_list(b"scaling_available_frequencies").iter().filter_map(|freq| freq.parse().ok()).collect(),
+        };
+
+        if result.current.is_some() || result.is_scaled() {
+            return Some(result);
+        }
+    }
+
+    let cpuinfo = fs::read(&[root, b"/proc/cpuinfo"])?;
+    cpuinfo_khz(&cpuinfo, cpu).map(|current| Frequency {
+        current: Some(current),
+        ..Frequency::default()
+    })
+}
+
+#[inline]
+///Returns frequency of `cpu`.
+///
+///Uses `/sys/devices/system/cpu/cpu*/cpufreq`, falling back to `cpu MHz` in `/proc/cpuinfo`.
+///
+///Returns `None` if frequency is unavailable, which is common for virtual machines.
+pub fn frequency(cpu: usize) -> Option<Frequency> {
+    frequency_with_root("", cpu)
+}
@@ -0,0 +1,101 @@
+//! CPU identification.
+
+extern crate alloc;
+
+use alloc::vec::Vec;
+
+pub use crate::data::cpu_info::{Info, Feature};
+#[cfg(any(target_arch = "x86", target_arch = "x86_64"))]
+pub use crate::data::cpu_info::has_feature;
+#[cfg(any(target_arch = "x86", target_arch = "x86_64"))]
+use crate::data::cpu_info::{x86, parse_number};
+use crate::unix::linux::fs;
+
+///Reads information of each CPU from `/proc/cpuinfo`.
+///
+///Feature flags reflect features, supported by both CPU and kernel.
+pub fn info_all() -> Option<Vec<Info>> {
+    fs::read(&[b"/proc/cpuinfo"]).map(|content| Info::parse(&content))
+}
+
+pub(super) fn current_cpu() -> usize {
+    match unsafe { libc::sched_getcpu() } {
+        cpu if cpu >= 0 => cpu as usize,
+        _ => 0,
+    }
+}
+
+#[cfg(any(target_arch = "x86", target_arch = "x86_64"))]
+///Identifies CPU, the calling thread runs on.
+///
+///Uses CPUID instruction, while microcode revision is read from `sysfs` or `/proc/cpuinfo`.
+pub fn info() -> Option<Info> {
+    let cpu = current_cpu();
+    let mut result = x86::info(cpu);
+
+    let mut buffer = [0u8; fs::NUMBER_SIZE];
+    let index = fs::format_number(cpu, &mut buffer);
+    result.microcode = fs::read(&[b"/sys/devices/system/cpu/cpu", index, b"/microcode/version"])
+        .and_then(|content| parse_number(core::str::from_utf8(&content).ok()?.trim()))
+        .or_else(|| info_all()?.into_iter().find(|info| info.cpu == cpu)?.microcode);
+
+    Some(result)
+}
+
+#[cfg(not(any(target_arch = "x86", target_arch = "x86_64")))]
+///Identifies CPU, the calling thread runs on.
+///
+///Uses `/proc/cpuinfo`.
+pub fn info() -> Option<Info> {
+    let cpu = current_cpu();
+    let mut infos = info_all()?;
+    match infos.iter().position(|info| info.cpu == cpu) {
+        Some(idx) => Some(infos.swap_remove(idx)),
+        None => infos.into_iter().next(),
+    }
+}
+
+#[cfg(not(any(target_arch = "x86", target_arch = "x86_64")))]
+///Returns whether feature flag `name` is listed for `cpu` in content of `/proc/cpuinfo`.
+///
+///Falls back to the first listed flags, when there is no entry for `cpu`.
+fn has_cpuinfo_flag(content: &[u8], cpu: usize, name: &str) -> bool {
+    let content = match core::str::from_utf8(content) {
+        Ok(content) => content,
+        Err(_) => return false,
+    };
+
+    let mut current = None;
+    let mut first = None;
+    for line in content.lines() {
+        let (key, value) = match line.split_once(':') {
+            Some((key, value)) => (key.trim(), value),
+            None => continue,
+        };
+
+        match key {
+            "processor" => current = value.trim().parse::<usize>().ok(),
+            "flags" | "Features" | "isa" => {
+                let has_flag = value.split_whitespace().any(|flag| flag == name);
+                if current == Some(cpu) {
+                    return has_flag;
+                }
+                first.get_or_insert(has_flag);
+            },
+            _ => (),
+        }
+    }
+
+    first.unwrap_or(false)
+}
+
+#[cfg(not(any(target_arch = "x86", target_arch = "x86_64")))]
+///Returns whether CPU, the calling thread runs on, has specified feature.
+///
+///Uses feature flags of the CPU in `/proc/cpuinfo`.
+pub fn has_feature(feature: Feature) -> bool {
+    match fs::read(&[b"/proc/cpuinfo"]) {
+        Some(content) => has_cpuinfo_flag(&content, current_cpu(), feature.name()),
+        None => false,
+    }
+}
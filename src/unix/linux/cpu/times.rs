@@ -0,0 +1,163 @@
+//! CPU time accounting, read from `/proc/stat`.
+
+extern crate alloc;
+
+use alloc::vec::Vec;
+
+use core::time::Duration;
+
+use crate::unix::linux::fs;
+
+const DEFAULT_TICKS_PER_SEC: u64 = 100;
+
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq)]
+///Time CPU spent in each state since boot.
+pub struct Times {
+    ///Time spent in user mode.
+    ///
+    ///Includes `guest` time.
+    pub user: Duration,
+    ///Time spent in user mode with low priority.
+    ///
+    ///Includes `guest_nice` time.
+    pub nice: Duration,
+    ///Time spent in kernel mode.
+    pub system: Duration,
+    ///Time spent idle.
+    pub idle: Duration,
+    ///Time spent idle, while waiting for I/O to complete.
+    pub iowait: Duration,
+    ///Time spent servicing hardware interrupts.
+    pub irq: Duration,
+    ///Time spent servicing software interrupts.
+    pub softirq: Duration,
+    ///Time stolen by hypervisor for other virtual machines.
+    pub steal: Duration,
+    ///Time spent running virtual CPU of guest.
+    pub guest: Duration,
+    ///Time spent running virtual CPU of guest with low priority.
+    pub guest_nice: Duration,
+}
+
+impl Times {
+    ///Parses CPU line of `/proc/stat`, without label, using `ticks_per_sec` to convert values.
+    fn parse(values: &str, ticks_per_sec: u64) -> Option<Self> {
+        let to_duration = |ticks: u64| Duration::new(ticks / ticks_per_sec, ((ticks % ticks_per_sec) * 1_000_000_000 / ticks_per_sec) as u32);
+
+        let mut values = values.split_whitespace().map(|value| value.parse::<u64>().ok().map(to_duration));
+        let mut next = |is_required: bool| match values.next() {
+            Some(value) => value,
+            //Older kernels do not report newer fields
+            None if !is_required => Some(Duration::ZERO),
+            None => None,
+        };
+
+        Some(Self {
+            user: next(true)?,
+            nice: next(true)?,
+            system: next(true)?,
+            idle: next(true)?,
+            iowait: next(false)?,
+            irq: next(false)?,
+            softirq: next(false)?,
+            steal: next(false)?,
+            guest: next(false)?,
+            guest_nice: next(false)?,
+        })
+    }
+
+    #[inline]
+    ///Returns total time, accounted for CPU.
+    ///
+    ///Guest time is not included, as it is already part of user time.
+    pub fn total(&self) -> Duration {
+        self.user + self.nice + self.system + self.idle + self.iowait + self.irq + self.softirq + self.steal
+    }
+
+    #[inline]
+    ///Returns time CPU was not idle, i.e. total time without `idle` and `iowait`.
+    pub fn busy(&self) -> Duration {
+        self.total().saturating_sub(self.idle + self.iowait)
+    }
+}
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+///CPU statistics from `/proc/stat`.
+pub struct Stats {
+    ///Times, aggregated over all CPUs.
+    pub total: Times,
+    ///Times of each online CPU, identified by its index.
+    pub cpus: Vec<(usize, Times)>,
+    ///Number of context switches since boot.
+    pub ctxt: u64,
+    ///Number of processes and threads created since boot.
+    pub processes: u64,
+    ///Number of threads, currently running or ready to run.
+    pub procs_running: u32,
+    ///Number of threads, currently blocked waiting for I/O.
+    pub procs_blocked: u32,
+}
+
+impl Stats {
+    ///Parses content of `/proc/stat`, using `ticks_per_sec` to convert times.
+    ///
+    ///Returns `None` if aggregate CPU times are missing or invalid.
+    pub fn parse(content: &[u8], ticks_per_sec: u64) -> Option<Self> {
+        let content = core::str::from_utf8(content).ok()?;
+        let ticks_per_sec = match ticks_per_sec {
+            0 => DEFAULT_TICKS_PER_SEC,
+            ticks_per_sec => ticks_per_sec,
+        };
+
+        let mut total = None;
+        let mut result = Self {
+            total: Times::default(),
+            cpus: Vec::new(),
+            ctxt: 0,
+            processes: 0,
+            procs_running: 0,
+            procs_blocked: 0,
+        };
+
+        for line in content.lines() {
+            let (label, values) = match line.split_once(' ') {
+                Some(line) => line,
+                None => continue,
+            };
+
+            match label {
+                "cpu" => total = Some(Times::parse(values, ticks_per_sec)?),
+                "ctxt" => result.ctxt = values.trim().parse().ok()?,
+                "processes" => result.processes = values.trim().parse().ok()?,
+                "procs_running" => result.procs_running = values.trim().parse().ok()?,
+                "procs_blocked" => result.procs_blocked = values.trim().parse().ok()?,
+                label => if let Some(cpu) = label.strip_prefix("cpu") {
+                    let cpu = cpu.parse().ok()?;
+                    result.cpus.push((cpu, Times::parse(values, ticks_per_sec)?));
+                },
+            }
+        }
+
+        result.total = total?;
+        Some(result)
+    }
+
+    ///Reads CPU statistics from `/proc/stat`.
+    ///
+    ///Times are converted using `_SC_CLK_TCK`.
+    pub fn new() -> Option<Self> {
+        let content = fs::read(&[b"/proc/stat"])?;
+        let ticks_per_sec = match unsafe { libc::sysconf(libc::_SC_CLK_TCK) } {
+            ticks if ticks > 0 => ticks as u64,
+            _ => DEFAULT_TICKS_PER_SEC,
+        };
+
+        Self::parse(&content, ticks_per_sec)
+    }
+
+    #[inline]
+    ///Returns times of CPU with specified index, if it is online.
+    pub fn cpu(&self, cpu: usize) -> Option<&Times> {
+        self.cpus.iter().find(|(idx, _)| *idx == cpu).map(|(_, times)| times)
+    }
+}
@@ -0,0 +1,158 @@
+//! CPU utilization, calculated out of consequent `/proc/stat` snapshots.
+
+extern crate alloc;
+
+use alloc::vec::Vec;
+
+use core::time::Duration;
+
+use super::{Stats, Times};
+
+#[derive(Debug, Clone, Copy, Default, PartialEq)]
+///CPU utilization in percents, split by category.
+///
+///All categories sum up to 100, unless CPU made no progress, in which case all are 0.
+pub struct Usage {
+    ///Time spent in user mode, including low priority and guest time.
+    pub user: f64,
+    ///Time spent in kernel mode, including interrupts.
+    pub system: f64,
+    ///Time spent idle, while waiting for I/O.
+    pub iowait: f64,
+    ///Time stolen by hypervisor.
+    pub steal: f64,
+    ///Time spent idle.
+    pub idle: f64,
+}
+
+impl Usage {
+    fn new(old: &Times, new: &Times) -> Self {
+        //Counters may go backwards on CPU hotplug, which is treated as no progress
+        let delta = Times {
+            user: new.user.saturating_sub(old.user),
+            nice: new.nice.saturating_sub(old.nice),
+            system: new.system.saturating_sub(old.system),
+            idle: new.idle.saturating_sub(old.idle),
+            iowait: new.iowait.saturating_sub(old.iowait),
+            irq: new.irq.saturating_sub(old.irq),
+            softirq: new.softirq.saturating_sub(old.softirq),
+            steal: new.steal.saturating_sub(old.steal),
+            guest: new.guest.saturating_sub(old.guest),
+            guest_nice: new.guest_nice.saturating_sub(old.guest_nice),
+        };
+
+        let total = delta.total();
+        if total == Duration::ZERO {
+            return Self::default();
+        }
+
+        let total = total.as_secs_f64();
+        let percent = |time: Duration| time.as_secs_f64() * 100.0 / total;
+        Self {
+            user: percent(delta.user + delta.nice),
+            system: percent(delta.system + delta.irq + delta.softirq),
+            iowait: percent(delta.iowait),
+            steal: percent(delta.steal),
+            idle: percent(delta.idle),
+        }
+    }
+
+    #[inline]
+    ///Returns percentage of time CPU was busy, i.e. neither idle nor waiting for I/O.
+    pub fn busy(&self) -> f64 {
+        self.user + self.system + self.steal
+    }
+}
+
+#[derive(Debug, Clone, Default, PartialEq)]
+///CPU utilization between two snapshots.
+pub struct CpuUsage {
+    ///Utilization, aggregated over CPUs online in both snapshots.
+    pub total: Usage,
+    ///Utilization of each CPU online in both snapshots, identified by its index.
+    pub cpus: Vec<(usize, Usage)>,
+}
+
+impl CpuUsage {
+    ///Calculates utilization between `old` and `new` snapshots.
+    pub fn new(old: &Stats, new: &Stats) -> Self {
+        let mut result = Self::default();
+        //Sum only CPUs present in both snapshots, as aggregate changes whenever CPU goes offline
+        let mut old_total = Times::default();
+        let mut new_total = Times::default();
+
+        for (cpu, new_times) in new.cpus.iter() {
+            let old_times = match old.cpu(*cpu) {
+                Some(old_times) => old_times,
+                None => continue,
+            };
+
+            result.cpus.push((*cpu, Usage::new(old_times, new_times)));
+            add(&mut old_total, old_times);
+            add(&mut new_total, new_times);
+        }
+
+        result.total = match result.cpus.is_empty() {
+            true => Usage::new(&old.total, &new.total),
+            false => Usage::new(&old_total, &new_total),
+        };
+        result
+    }
+
+    #[inline]
+    ///Returns utilization of CPU with specified index, if it is available.
+    pub fn cpu(&self, cpu: usize) -> Option<&Usage> {
+        self.cpus.iter().find(|(idx, _)| *idx == cpu).map(|(_, usage)| usage)
+    }
+}
+
+fn add(sum: &mut Times, times: &Times) {
+    sum.user += times.user;
+    sum.nice += times.nice;
+    sum.system += times.system;
+    sum.idle += times.idle;
+    sum.iowait += times.iowait;
+    sum.irq += times.irq;
+    sum.softirq += times.softirq;
+    sum.steal += times.steal;
+    sum.guest += times.guest;
+    sum.guest_nice += times.guest_nice;
+}
+
+#[derive(Debug, Clone)]
+///CPU utilization sampler, remembering previous snapshot of `/proc/stat`.
+pub struct UsageSampler {
+    previous: Stats,
+}
+
+impl UsageSampler {
+    #[inline]
+    ///Creates sampler, taking initial snapshot.
+    ///
+    ///Returns `None` if `/proc/stat` cannot be read.
+    pub fn new() -> Option<Self> {
+        Stats::new().map(Self::with_stats)
+    }
+
+    #[inline(always)]
+    ///Creates sampler, using provided initial snapshot.
+    pub fn with_stats(stats: Stats) -> Self {
+        Self {
+            previous: stats,
+        }
+    }
+
+    ///Calculates utilization since previous snapshot, remembering `stats` as new one.
+    pub fn update(&mut self, stats: Stats) -> CpuUsage {
+        let result = CpuUsage::new(&self.previous, &stats);
+        self.previous = stats;
+        result
+    }
+
+    ///Takes new snapshot and returns utilization since previous one.
+    ///
+    ///Returns `None` if `/proc/stat` cannot be read.
+    pub fn sample(&mut self) -> Option<CpuUsage> {
+        Stats::new().map(|stats| self.update(stats))
+    }
+}
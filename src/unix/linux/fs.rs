@@ -0,0 +1,140 @@
+//! Helpers to read procfs and sysfs.
+
+extern crate alloc;
+
+use alloc::vec::Vec;
+
+use core::str::FromStr;
+
+const READ_CHUNK: usize = 4096;
+
+///Builds null terminated path out of its parts.
+pub(crate) fn c_path(parts: &[&[u8]]) -> Vec<u8> {
+    let len = parts.iter().fold(1, |len, part| len + part.len());
+    let mut path = Vec::with_capacity(len);
+    for part in parts {
+        path.extend_from_slice(part);
+    }
+    path.push(0);
+    path
+}
+
+///Reads whole file, joining path out of `parts`.
+///
+///In case of failure, `errno` contains reason.
+pub(crate) fn read(parts: &[&[u8]]) -> Option<Vec<u8>> {
+    let path = c_path(parts);
+    let fd = unsafe {
+        libc::open(path.as_ptr() as _, libc::O_RDONLY | libc::O_CLOEXEC)
+    };
+
+    if fd == -1 {
+        return None;
+    }
+
+    let mut result = Vec::<u8>::new();
+    loop {
+        result.reserve(READ_CHUNK);
+        let spare = result.capacity() - result.len();
+        let res = unsafe {
+            libc::read(fd, result.as_mut_ptr().add(result.len()) as _, spare)
+        };
+
+        match res {
+            0 => break,
+            res if res < 0 => {
+                if errno() == libc::EINTR {
+                    continue;
+                }
+
+                unsafe {
+                    libc::close(fd);
+                }
+                return None;
+            },
+            res => unsafe {
+                result.set_len(result.len() + res as usize);
+            }
+        }
+    }
+
+    unsafe {
+        libc::close(fd);
+    }
+    Some(result)
+}
+
+///Returns whether path, joined out of `parts`, exists.
+pub(crate) fn exists(parts: &[&[u8]]) -> bool {
+    let path = c_path(parts);
+    unsafe {
+        libc::access(path.as_ptr() as _, libc::F_OK) == 0
+    }
+}
+
+#[inline]
+///Returns whether name can be used as single path component.
+pub(crate) fn is_valid_name(name: &[u8]) -> bool {
+    !name.is_empty() && !name.contains(&b'/') && !name.contains(&0) && name != b"." && name != b".."
+}
+
+///Reads file and parses its trimmed content as single value.
+pub(crate) fn read_value<T: FromStr>(parts: &[&[u8]]) -> Option<T> {
+    let content = read(parts)?;
+    parse_value(&content)
+}
+
+///Parses trimmed content as single value.
+pub(crate) fn parse_value<T: FromStr>(content: &[u8]) -> Option<T> {
+    core::str::from_utf8(content).ok()?.trim().parse().ok()
+}
+
+#[inline]
+///Returns last OS error code.
+pub(crate) fn errno() -> libc::c_int {
+    #[cfg(target_os = "android")]
+    unsafe {
+        *libc::__errno()
+    }
+    #[cfg(not(target_os = "android"))]
+    unsafe {
+        *libc::__errno_location()
+    }
+}
+
+///Reads target of symbolic link, joining path out of `parts`.
+///
+///In case of failure, `errno` contains reason.
+pub(crate) fn read_link(parts: &[&[u8]]) -> Option<Vec<u8>> {
+    let path = c_path(parts);
+    let mut result = Vec::<u8>::with_capacity(libc::PATH_MAX as usize);
+    let res = unsafe {
+        libc::readlink(path.as_ptr() as _, result.as_mut_ptr() as _, result.capacity())
+    };
+
+    match res {
+        res if res < 0 => None,
+        res => unsafe {
+            result.set_len(res as usize);
+            Some(result)
+        }
+    }
+}
+
+///Size of buffer, sufficient to format any `usize`.
+pub(crate) const NUMBER_SIZE: usize = 20;
+
+///Formats decimal number into `buffer`, to be used as part of path.
+pub(crate) fn format_number(mut value: usize, buffer: &mut [u8; NUMBER_SIZE]) -> &[u8] {
+    let mut start = buffer.len();
+    loop {
+        start -= 1;
+        buffer[start] = b'0' + (value % 10) as u8;
+        value /= 10;
+        if value == 0 {
+            break;
+        }
+    }
+
+    &buffer[start..]
+}
@@ -0,0 +1,127 @@
+//! Host information.
+
+pub use crate::unix::posix::host::*;
+
+use core::mem;
+use core::time::Duration;
+
+use crate::unix::linux::fs;
+
+mod virt;
+pub use virt::{virtualization, Virtualization, Hypervisor, Container};
+mod dmi;
+pub use dmi::{Dmi, DmiError, DmiField, ChassisType};
+mod time;
+pub use time::{time_info, TimeInfo, ClockSync};
+mod psi;
+pub use psi::{pressure, cgroup_pressure, Pressure, Stall, Resource, PsiError, Trigger, StallKind};
+
+impl OsRelease {
+    ///Reads operating system identification from `/etc/os-release`, falling back to `/usr/lib/os-release`.
+    ///
+    ///Returns `None` if neither file can be read.
+    pub fn new() -> Option<Self> {
+        fs::read(&[b"/etc/os-release"]).or_else(|| fs::read(&[b"/usr/lib/os-release"]))
+                                        .map(|content| Self::parse(&content))
+    }
+}
+
+///Parses fractional number of seconds, as used by `/proc/uptime`.
+fn parse_seconds(value: &str) -> Option<Duration> {
+    let (secs, fraction) = value.split_once('.').unwrap_or((value, ""));
+    let secs = secs.parse().ok()?;
+
+    let mut nanos = 0u32;
+    let mut scale = 100_000_000;
+    for digit in fraction.bytes().take(9) {
+        if !digit.is_ascii_digit() {
+            return None;
+        }
+        nanos += (digit - b'0') as u32 * scale;
+        scale /= 10;
+    }
+
+    Some(Duration::new(secs, nanos))
+}
+
+///Returns time elapsed since boot, including time spent in suspend.
+///
+///Uses `CLOCK_BOOTTIME`, falling back to `/proc/uptime` and `sysinfo(2)`.
+pub fn uptime() -> Option<Duration> {
+    let mut time = mem::MaybeUninit::<libc::timespec>::uninit();
+    if unsafe { libc::clock_gettime(libc::CLOCK_BOOTTIME, time.as_mut_ptr()) } == 0 {
+        let time = unsafe {
+            time.assume_init()
+        };
+        return Some(Duration::new(time.tv_sec as u64, time.tv_nsec as u32));
+    }
+
+    let uptime = fs::read(&[b"/proc/uptime"]).and_then(|content| {
+        let content = core::str::from_utf8(&content).ok()?;
+        parse_seconds(content.split_whitespace().next()?)
+    });
+    if uptime.is_some() {
+        return uptime;
+    }
+
+    let mut info = mem::MaybeUninit::<libc::sysinfo>::uninit();
+    if unsafe { libc::sysinfo(info.as_mut_ptr()) } == 0 {
+        let info = unsafe {
+            info.assume_init()
+        };
+        Some(Duration::from_secs(info.uptime as u64))
+    } else {
+        None
+    }
+}
+
+///Returns boot time as duration since Unix epoch.
+///
+///Uses `btime` from `/proc/stat`.
+pub fn boot_time() -> Option<Duration> {
+    let content = fs::read(&[b"/proc/stat"])?;
+    let content = core::str::from_utf8(&content).ok()?;
+    content.lines()
+           .find_map(|line| line.strip_prefix("btime "))
+           .and_then(|btime| btime.trim().parse().ok())
+           .map(Duration::from_secs)
+}
+
+///Returns system load averages, using `getloadavg`, together with task counts and last PID from `/proc/loadavg`.
+///
+///Averages are taken from `/proc/loadavg` as well, if `getloadavg` is not available.
+pub fn load_average() -> Option<LoadAverage> {
+    let proc_load = fs::read(&[b"/proc/loadavg"]).and_then(|content| LoadAverage::parse(&content));
+
+    #[cfg(not(target_os = "android"))]
+    if let Some(mut load) = crate::unix::posix::host::load_average() {
+        if let Some(proc_load) = proc_load {
+            load.running = proc_load.running;
+            load.total = proc_load.total;
+            load.last_pid = proc_load.last_pid;
+        }
+        return Some(load);
+    }
+
+    proc_load
+}
+
+fn read_id(path: &[u8], parse: fn(&[u8]) -> Option<Id128>) -> Option<Id128> {
+    let content = fs::read(&[path])?;
+    match parse(&content) {
+        Some(id) if !id.is_null() => Some(id),
+        _ => None,
+    }
+}
+
+///Returns machine ID from `/etc/machine-id`, falling back to `/var/lib/dbus/machine-id`.
+///
+///Machine ID is considered confidential, prefer to use `Id128::app_specific` when exposing it.
+pub fn machine_id() -> Option<Id128> {
+    read_id(b"/etc/machine-id", Id128::parse_machine_id).or_else(|| read_id(b"/var/lib/dbus/machine-id", Id128::parse_machine_id))
+}
+
+///Returns ID of current boot from `/proc/sys/kernel/random/boot_id`.
+pub fn boot_id() -> Option<Id128> {
+    read_id(b"/proc/sys/kernel/random/boot_id", Id128::parse)
+}
@@ -0,0 +1,239 @@
+//! DMI/SMBIOS hardware identity.
+
+extern crate alloc;
+
+use alloc::string::String;
+
+use core::fmt;
+
+use super::Id128;
+use crate::unix::linux::fs;
+
+const DMI_DIR: &[u8] = b"/sys/class/dmi/id";
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+///Reason DMI field cannot be read.
+pub enum DmiError {
+    ///Field is readable only by privileged user.
+    PermissionDenied,
+    ///Field is not provided by firmware or cannot be parsed.
+    Unavailable,
+}
+
+impl fmt::Display for DmiError {
+    #[inline]
+    fn fmt(&self, fmt: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            DmiError::PermissionDenied => fmt.write_str("permission denied"),
+            DmiError::Unavailable => fmt.write_str("unavailable"),
+        }
+    }
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+///Chassis type, as defined by SMBIOS specification.
+pub enum ChassisType {
+    ///Other.
+    Other,
+    ///Unknown.
+    Unknown,
+    ///Desktop.
+    Desktop,
+    ///Low profile desktop.
+    LowProfileDesktop,
+    ///Pizza box.
+    PizzaBox,
+    ///Mini tower.
+    MiniTower,
+    ///Tower.
+    Tower,
+    ///Portable.
+    Portable,
+    ///Laptop.
+    Laptop,
+    ///Notebook.
+    Notebook,
+    ///Hand held.
+    HandHeld,
+    ///Docking station.
+    DockingStation,
+    ///All in one.
+    AllInOne,
+    ///Sub notebook.
+    SubNotebook,
+    ///Space-saving.
+    SpaceSaving,
+    ///Lunch box.
+    LunchBox,
+    ///Main server chassis.
+    MainServer,
+    ///Expansion chassis.
+    Expansion,
+    ///Sub chassis.
+    SubChassis,
+    ///Bus expansion chassis.
+    BusExpansion,
+    ///Peripheral chassis.
+    Peripheral,
+    ///RAID chassis.
+    Raid,
+    ///Rack mount chassis.
+    RackMount,
+    ///Sealed-case PC.
+    SealedCasePc,
+    ///Multi-system chassis.
+    MultiSystem,
+    ///Compact PCI.
+    CompactPci,
+    ///Advanced TCA.
+    AdvancedTca,
+    ///Blade.
+    Blade,
+    ///Blade enclosure.
+    BladeEnclosure,
+    ///Tablet.
+    Tablet,
+    ///Convertible.
+    Convertible,
+    ///Detachable.
+    Detachable,
+    ///IoT gateway.
+    IotGateway,
+    ///Embedded PC.
+    EmbeddedPc,
+    ///Mini PC.
+    MiniPc,
+    ///Stick PC.
+    StickPc,
+    ///Type, unknown to this library.
+    Unrecognized(u8),
+}
+
+impl ChassisType {
+    ///Creates chassis type out of SMBIOS value.
+    pub const fn from_value(value: u8) -> Self {
+        //Most significant bit indicates presence of chassis lock
+        match value & 0x7f {
+            1 => ChassisType::Other,
+            2 => ChassisType::Unknown,
+            3 => ChassisType::Desktop,
+            4 => ChassisType::LowProfileDesktop,
+            5 => ChassisType::PizzaBox,
+            6 => ChassisType::MiniTower,
+            7 => ChassisType::Tower,
+            8 => ChassisType::Portable,
+            9 => ChassisType::Laptop,
+            10 => ChassisType::Notebook,
+            11 => ChassisType::HandHeld,
+            12 => ChassisType::DockingStation,
+            13 => ChassisType::AllInOne,
+            14 => ChassisType::SubNotebook,
+            15 => ChassisType::SpaceSaving,
+            16 => ChassisType::LunchBox,
+            17 => ChassisType::MainServer,
+            18 => ChassisType::Expansion,
+            19 => ChassisType::SubChassis,
+            20 => ChassisType::BusExpansion,
+            21 => ChassisType::Peripheral,
+            22 => ChassisType::Raid,
+            23 => ChassisType::RackMount,
+            24 => ChassisType::SealedCasePc,
+            25 => ChassisType::MultiSystem,
+            26 => ChassisType::CompactPci,
+            27 => ChassisType::AdvancedTca,
+            28 => ChassisType::Blade,
+            29 => ChassisType::BladeEnclosure,
+            30 => ChassisType::Tablet,
+            31 => ChassisType::Convertible,
+            32 => ChassisType::Detachable,
+            33 => ChassisType::IotGateway,
+            34 => ChassisType::EmbeddedPc,
+            35 => ChassisType::MiniPc,
+            36 => ChassisType::StickPc,
+            value => ChassisType::Unrecognized(value),
+        }
+    }
+}
+
+///Value of DMI field.
+pub type DmiField<T> = Result<T, DmiError>;
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+///Hardware identity, provided by DMI/SMBIOS.
+///
+///Each field is read independently, so that fields restricted to privileged user (e.g. serial
+///number or UUID) result in `DmiError::PermissionDenied` without affecting others.
+pub struct Dmi {
+    ///System manufacturer (`sys_vendor`).
+    pub sys_vendor: DmiField<String>,
+    ///Product name (`product_name`).
+    pub product_name: DmiField<String>,
+    ///Product version (`product_version`).
+    pub product_version: DmiField<String>,
+    ///Product serial number (`product_serial`), readable only by root.
+    pub product_serial: DmiField<String>,
+    ///System UUID (`product_uuid`), readable only by root.
+    pub product_uuid: DmiField<Id128>,
+    ///Baseboard manufacturer (`board_vendor`).
+    pub board_vendor: DmiField<String>,
+    ///Baseboard name (`board_name`).
+    pub board_name: DmiField<String>,
+    ///BIOS vendor (`bios_vendor`).
+    pub bios_vendor: DmiField<String>,
+    ///BIOS version (`bios_version`).
+    pub bios_version: DmiField<String>,
+    ///BIOS release date (`bios_date`), commonly in `MM/DD/YYYY` format.
+    pub bios_date: DmiField<String>,
+    ///Chassis type (`chassis_type`).
+    pub chassis_type: DmiField<ChassisType>,
+}
+
+fn read_field(root: &[u8], name: &[u8]) -> DmiField<String> {
+    let content = match fs::read(&[root, DMI_DIR, b"/", name]) {
+        Some(content) => content,
+        None => return match fs::errno() {
+            libc::EACCES | libc::EPERM => Err(DmiError::PermissionDenied),
+            _ => Err(DmiError::Unavailable),
+        },
+    };
+
+    let content = String::from_utf8_lossy(&content);
+    match content.trim() {
+        "" => Err(DmiError::Unavailable),
+        content => Ok(content.into()),
+    }
+}
+
+impl Dmi {
+    ///Reads DMI information from `sysfs`, mounted at `root`.
+    ///
+    ///Returns `None` if DMI is not supported by system.
+    pub fn with_root(root: &str) -> Option<Self> {
+        let root = root.as_bytes();
+        if !fs::exists(&[root, DMI_DIR]) {
+            return None;
+        }
+
+        Some(Self {
+            sys_vendor: read_field(root, b"sys_vendor"),
+            product_name: read_field(root, b"product_name"),
+            product_version: read_field(root, b"product_version"),
+            product_serial: read_field(root, b"product_serial"),
+            product_uuid: read_field(root, b"product_uuid").and_then(|uuid| Id128::parse(uuid.as_bytes()).ok_or(DmiError::Unavailable)),
+            board_vendor: read_field(root, b"board_vendor"),
+            board_name: read_field(root, b"board_name"),
+            bios_vendor: read_field(root, b"bios_vendor"),
+            bios_version: read_field(root, b"bios_version"),
+            bios_date: read_field(root, b"bios_date"),
+            chassis_type: read_field(root, b"chassis_type").and_then(|value| value.parse().map(ChassisType::from_value).map_err(|_| DmiError::Unavailable)),
+        })
+    }
+
+    #[inline(always)]
+    ///Reads DMI information from `/sys/class/dmi/id`.
+    ///
+    ///Returns `None` if DMI is not supported by system.
+    pub fn new() -> Option<Self> {
+        Self::with_root("")
+    }
+}
@@ -0,0 +1,297 @@
+//! Pressure Stall Information.
+
+#[cfg(feature = "std")]
+extern crate std;
+extern crate alloc;
+
+use alloc::vec::Vec;
+
+use core::fmt;
+use core::time::Duration;
+
+use crate::unix::linux::cgroup::Cgroup;
+use crate::unix::linux::fs;
+
+const PRESSURE_DIR: &[u8] = b"/proc/pressure/";
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+///Resource, which pressure is tracked.
+pub enum Resource {
+    ///CPU.
+    Cpu,
+    ///Memory.
+    Memory,
+    ///Block I/O.
+    Io,
+    ///Hardware and software interrupts.
+    ///
+    ///Only `full` pressure is reported.
+    Irq,
+}
+
+impl Resource {
+    #[inline]
+    ///Returns resource's name, as used by pressure files.
+    pub const fn name(&self) -> &'static str {
+        match self {
+            Resource::Cpu => "cpu",
+            Resource::Memory => "memory",
+            Resource::Io => "io",
+            Resource::Irq => "irq",
+        }
+    }
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+///Pressure information error.
+pub enum PsiError {
+    ///Kernel is built without PSI, it is disabled or resource is not tracked.
+    Unsupported,
+    ///Access is denied, e.g. creating trigger requires `CAP_SYS_RESOURCE` on older kernels.
+    PermissionDenied,
+    ///Content cannot be parsed or trigger is rejected by kernel.
+    Invalid,
+    ///Other OS error, identified by `errno`.
+    Os(i32),
+}
+
+impl PsiError {
+    fn last_os_error() -> Self {
+        match fs::errno() {
+            libc::ENOENT | libc::EOPNOTSUPP => PsiError::Unsupported,
+            libc::EACCES | libc::EPERM => PsiError::PermissionDenied,
+            libc::EINVAL => PsiError::Invalid,
+            errno => PsiError::Os(errno),
+        }
+    }
+}
+
+impl fmt::Display for PsiError {
+    #[inline]
+    fn fmt(&self, fmt: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            PsiError::Unsupported => fmt.write_str("unsupported"),
+            PsiError::PermissionDenied => fmt.write_str("permission denied"),
+            PsiError::Invalid => fmt.write_str("invalid"),
+            PsiError::Os(errno) => fmt.write_fmt(format_args!("os error {}", errno)),
+        }
+    }
+}
+
+#[derive(Debug, Clone, Copy, Default, PartialEq)]
+///Share of time tasks were stalled on resource.
+pub struct Stall {
+    ///Percentage of time stalled over last 10 seconds.
+    pub avg10: f64,
+    ///Percentage of time stalled over last 60 seconds.
+    pub avg60: f64,
+    ///Percentage of time stalled over last 300 seconds.
+    pub avg300: f64,
+    ///Total time stalled.
+    pub total: Duration,
+}
+
+impl Stall {
+    ///Parses values of single pressure line, without its label.
+    fn parse(values: &str) -> Option<Self> {
+        let mut result = Self::default();
+        for value in values.split_whitespace() {
+            match value.split_once('=')? {
+                ("avg10", value) => result.avg10 = value.parse().ok()?,
+                ("avg60", value) => result.avg60 = value.parse().ok()?,
+                ("avg300", value) => result.avg300 = value.parse().ok()?,
+                ("total", value) => result.total = Duration::from_micros(value.parse().ok()?),
+                _ => (),
+            }
+        }
+
+        Some(result)
+    }
+}
+
+#[derive(Debug, Clone, Copy, Default, PartialEq)]
+///Pressure of resource.
+pub struct Pressure {
+    ///Time, at least some tasks were stalled on resource.
+    ///
+    ///Not reported for `Resource::Irq`.
+    pub some: Option<Stall>,
+    ///Time, all non-idle tasks were stalled on resource simultaneously.
+    ///
+    ///Not reported for `Resource::Cpu` by kernels older than 5.13.
+    pub full: Option<Stall>,
+}
+
+impl Pressure {
+    ///Parses content of pressure file.
+    ///
+    ///Returns `None` if content is invalid or holds neither `some` nor `full` line.
+    pub fn parse(content: &[u8]) -> Option<Self> {
+        let content = core::str::from_utf8(content).ok()?;
+        let mut result = Self::default();
+
+        for line in content.lines() {
+            match line.split_once(' ') {
+                Some(("some", values)) => result.some = Some(Stall::parse(values)?),
+                Some(("full", values)) => result.full = Some(Stall::parse(values)?),
+                _ => (),
+            }
+        }
+
+        match result.some.is_none() && result.full.is_none() {
+            true => None,
+            false => Some(result),
+        }
+    }
+
+    fn read(parts: &[&[u8]]) -> Result<Self, PsiError> {
+        let content = fs::read(parts).ok_or_else(PsiError::last_os_error)?;
+        Self::parse(&content).ok_or(PsiError::Invalid)
+    }
+
+    #[inline]
+    ///Reads system-wide pressure of `resource` from `procfs`, mounted at `root`.
+    pub fn with_root(root: &str, resource: Resource) -> Result<Self, PsiError> {
+        Self::read(&[root.as_bytes(), PRESSURE_DIR, resource.name().as_bytes()])
+    }
+
+    ///Reads pressure of `resource` within control group of current process, using filesystems mounted at `root`.
+    ///
+    ///Requires cgroup v2, otherwise `PsiError::Unsupported` is returned.
+    pub fn cgroup_with_root(root: &str, resource: Resource) -> Result<Self, PsiError> {
+        let cgroup = Cgroup::unified(root.as_bytes()).ok_or(PsiError::Unsupported)?;
+        Self::read(&[cgroup.dir(), b"/", resource.name().as_bytes(), b".pressure"])
+    }
+}
+
+#[inline]
+///Returns system-wide pressure of `resource`, read from `/proc/pressure`.
+///
+///Returns `PsiError::Unsupported` if kernel has no PSI or it is disabled.
+pub fn pressure(resource: Resource) -> Result<Pressure, PsiError> {
+    Pressure::with_root("", resource)
+}
+
+#[inline]
+///Returns pressure of `resource` within control group of current process, read from its `*.pressure` file.
+///
+///Returns `PsiError::Unsupported` if kernel has no PSI or cgroup v2 is not available.
+pub fn cgroup_pressure(resource: Resource) -> Result<Pressure, PsiError> {
+    Pressure::cgroup_with_root("", resource)
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+///Kind of stall, tracked by trigger.
+pub enum StallKind {
+    ///At least some tasks are stalled.
+    Some,
+    ///All non-idle tasks are stalled.
+    Full,
+}
+
+#[derive(Debug)]
+///PSI trigger, notifying when stall time exceeds threshold within time window.
+///
+///Its file descriptor can be polled for `POLLPRI` directly or registered within event loop.
+///Trigger is removed when it is dropped.
+pub struct Trigger {
+    fd: libc::c_int,
+}
+
+impl Trigger {
+    fn open(parts: &[&[u8]], kind: StallKind, threshold: Duration, window: Duration) -> Result<Self, PsiError> {
+        let path = fs::c_path(parts);
+        let fd = unsafe {
+            libc::open(path.as_ptr() as _, libc::O_RDWR | libc::O_NONBLOCK | libc::O_CLOEXEC)
+        };
+        if fd == -1 {
+            return Err(PsiError::last_os_error());
+        }
+        let result = Self {
+            fd,
+        };
+
+        //Format: <some|full> <threshold us> <window us>
+        let mut buffer = [0u8; fs::NUMBER_SIZE];
+        let mut trigger = Vec::with_capacity(2 * fs::NUMBER_SIZE + 8);
+        trigger.extend_from_slice(match kind {
+            StallKind::Some => b"some ",
+            StallKind::Full => b"full ",
+        });
+        trigger.extend_from_slice(fs::format_number(threshold.as_micros() as usize, &mut buffer));
+        trigger.push(b' ');
+        trigger.extend_from_slice(fs::format_number(window.as_micros() as usize, &mut buffer));
+        trigger.push(0);
+
+        let res = unsafe {
+            libc::write(fd, trigger.as_ptr() as _, trigger.len())
+        };
+        match res {
+            -1 => Err(PsiError::last_os_error()),
+            _ => Ok(result),
+        }
+    }
+
+    #[inline]
+    ///Registers system-wide trigger on `resource`, firing when `kind` stall exceeds `threshold` within `window`.
+    ///
+    ///Kernel limits window to range from 500ms to 10s and, for unprivileged users, requires it to be multiple of 2s.
+    pub fn new(resource: Resource, kind: StallKind, threshold: Duration, window: Duration) -> Result<Self, PsiError> {
+        Self::open(&[PRESSURE_DIR, resource.name().as_bytes()], kind, threshold, window)
+    }
+
+    ///Registers trigger on `resource` within control group of current process.
+    ///
+    ///Requires cgroup v2, otherwise `PsiError::Unsupported` is returned.
+    pub fn cgroup(resource: Resource, kind: StallKind, threshold: Duration, window: Duration) -> Result<Self, PsiError> {
+        let cgroup = Cgroup::unified(b"").ok_or(PsiError::Unsupported)?;
+        Self::open(&[cgroup.dir(), b"/", resource.name().as_bytes(), b".pressure"], kind, threshold, window)
+    }
+
+    #[inline(always)]
+    ///Returns file descriptor, to be polled for `POLLPRI`.
+    pub fn fd(&self) -> libc::c_int {
+        self.fd
+    }
+
+    ///Waits until trigger fires, up to `timeout` or indefinitely if it is `None`.
+    ///
+    ///Returns `Some(true)` if trigger fired and `Some(false)` on timeout.
+    ///
+    ///Returns `None` if monitored control group is removed, otherwise in case of failure please check `std::io::Error::last_os_error()`
+    pub fn wait(&self, timeout: Option<Duration>) -> Option<bool> {
+        let mut poll = libc::pollfd {
+            fd: self.fd,
+            events: libc::POLLPRI,
+            revents: 0,
+        };
+        let timeout = match timeout {
+            Some(timeout) => core::cmp::min(timeout.as_millis(), libc::c_int::MAX as u128) as libc::c_int,
+            None => -1,
+        };
+
+        match unsafe { libc::poll(&mut poll, 1, timeout) } {
+            -1 => None,
+            0 => Some(false),
+            _ if poll.revents & libc::POLLERR != 0 => None,
+            _ => Some(poll.revents & libc::POLLPRI != 0),
+        }
+    }
+}
+
+impl Drop for Trigger {
+    #[inline]
+    fn drop(&mut self) {
+        unsafe {
+            libc::close(self.fd);
+        }
+    }
+}
+
+#[cfg(feature = "std")]
+impl std::os::unix::io::AsRawFd for Trigger {
+    #[inline(always)]
+    fn as_raw_fd(&self) -> std::os::unix::io::RawFd {
+        self.fd
+    }
+}
@@ -0,0 +1,148 @@
+//! Timezone, locale and system clock information.
+
+extern crate alloc;
+
+use alloc::string::String;
+
+use core::mem;
+use core::time::Duration;
+
+use super::OsRelease;
+use crate::unix::linux::fs;
+
+const ZONEINFO: &[u8] = b"zoneinfo/";
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+///System clock synchronization status, as reported by `adjtimex(2)`.
+pub struct ClockSync {
+    ///Whether kernel considers clock to be synchronized (e.g. by NTP daemon).
+    pub synchronized: bool,
+    ///Estimated error.
+    pub estimated_error: Duration,
+    ///Maximum error.
+    pub max_error: Duration,
+    ///Offset between TAI and UTC in seconds.
+    ///
+    ///Zero, unless set by NTP daemon.
+    pub tai_offset: i32,
+}
+
+impl ClockSync {
+    #[cfg(not(target_os = "android"))]
+    ///Retrieves clock synchronization status.
+    ///
+    ///In case of failure please check `std::io::Error::last_os_error()`
+    pub fn get() -> Option<Self> {
+        let mut timex = unsafe {
+            mem::MaybeUninit::<libc::timex>::zeroed().assume_init()
+        };
+        let state = unsafe {
+            libc::adjtimex(&mut timex)
+        };
+
+        if state == -1 {
+            return None;
+        }
+
+        Some(Self {
+            synchronized: state != libc::TIME_ERROR && timex.status & libc::STA_UNSYNC == 0,
+            estimated_error: Duration::from_micros(timex.esterror as u64),
+            max_error: Duration::from_micros(timex.maxerror as u64),
+            tai_offset: timex.tai,
+        })
+    }
+
+    #[cfg(target_os = "android")]
+    #[inline(always)]
+    ///Retrieves clock synchronization status.
+    ///
+    ///Not supported on Android, always returns `None`.
+    pub fn get() -> Option<Self> {
+        None
+    }
+}
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+///Time related host configuration.
+pub struct TimeInfo {
+    ///Configured timezone name (e.g. `Europe/Berlin`).
+    pub timezone: Option<String>,
+    ///Configured system locale (`LANG` from `/etc/locale.conf`).
+    pub locale: Option<String>,
+    ///Current offset from UTC in seconds, east of UTC being positive.
+    pub utc_offset: Option<i32>,
+    ///Clock synchronization status.
+    pub clock: Option<ClockSync>,
+}
+
+fn zone_name(target: &[u8]) -> Option<String> {
+    let start = target.windows(ZONEINFO.len()).rposition(|window| window == ZONEINFO)? + ZONEINFO.len();
+    let mut name = &target[start..];
+    //Alternative databases with and without leap seconds
+    for prefix in [b"posix/" as &[u8], b"right/"].iter() {
+        if let Some(rest) = name.strip_prefix(*prefix) {
+            name = rest;
+        }
+    }
+
+    match core::str::from_utf8(name) {
+        Ok("") | Err(_) => None,
+        Ok(name) => Some(name.into()),
+    }
+}
+
+fn read_timezone(root: &[u8]) -> Option<String> {
+    if let Some(name) = fs::read_link(&[root, b"/etc/localtime"]).and_then(|target| zone_name(&target)) {
+        return Some(name);
+    }
+
+    let content = fs::read(&[root, b"/etc/timezone"])?;
+    match core::str::from_utf8(&content).ok()?.trim() {
+        "" => None,
+        name => Some(name.into()),
+    }
+}
+
+fn read_locale(root: &[u8]) -> Option<String> {
+    let content = fs::read(&[root, b"/etc/locale.conf"]).or_else(|| fs::read(&[root, b"/etc/default/locale"]))?;
+    let config = OsRelease::parse(&content);
+    config.get("LANG").filter(|lang| !lang.is_empty()).map(Into::into)
+}
+
+fn utc_offset() -> Option<i32> {
+    let mut tm = mem::MaybeUninit::<libc::tm>::uninit();
+    unsafe {
+        let now = libc::time(core::ptr::null_mut());
+        if libc::localtime_r(&now, tm.as_mut_ptr()).is_null() {
+            return None;
+        }
+
+        Some(tm.assume_init().tm_gmtoff as i32)
+    }
+}
+
+impl TimeInfo {
+    ///Reads timezone and locale configuration from files, located at `root`.
+    ///
+    ///UTC offset and clock status are not available from files and are always `None`.
+    pub fn with_root(root: &str) -> Self {
+        let root = root.as_bytes();
+        Self {
+            timezone: read_timezone(root),
+            locale: read_locale(root),
+            utc_offset: None,
+            clock: None,
+        }
+    }
+}
+
+///Retrieves time related host configuration.
+///
+///Timezone is resolved from `/etc/localtime` symbolic link, falling back to `/etc/timezone`.
+///UTC offset is calculated using C library, hence it respects `TZ` environment variable.
+pub fn time_info() -> TimeInfo {
+    let mut result = TimeInfo::with_root("");
+    result.utc_offset = utc_offset();
+    result.clock = ClockSync::get();
+    result
+}
@@ -0,0 +1,345 @@
+//! Virtualization and container detection.
+
+use crate::unix::linux::fs;
+
+const DMI_FILES: [&[u8]; 5] = [
+    b"/sys/class/dmi/id/product_name",
+    b"/sys/class/dmi/id/sys_vendor",
+    b"/sys/class/dmi/id/board_vendor",
+    b"/sys/class/dmi/id/bios_vendor",
+    b"/sys/class/dmi/id/product_version",
+];
+
+const DMI_VENDORS: [(&[u8], Hypervisor); 15] = [
+    (b"KVM", Hypervisor::Kvm),
+    (b"OpenStack", Hypervisor::Kvm),
+    (b"KubeVirt", Hypervisor::Kvm),
+    (b"Amazon EC2", Hypervisor::AwsNitro),
+    (b"QEMU", Hypervisor::Qemu),
+    (b"VMware", Hypervisor::Vmware),
+    (b"VMW", Hypervisor::Vmware),
+    (b"innotek GmbH", Hypervisor::VirtualBox),
+    (b"VirtualBox", Hypervisor::VirtualBox),
+    (b"Oracle Corporation", Hypervisor::VirtualBox),
+    (b"Xen", Hypervisor::Xen),
+    (b"Hyper-V", Hypervisor::HyperV),
+    (b"Bochs", Hypervisor::Other),
+    (b"Parallels", Hypervisor::Other),
+    (b"BHYVE", Hypervisor::Other),
+];
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+///Hypervisor, the system is running under.
+pub enum Hypervisor {
+    ///Bare metal.
+    None,
+    ///Linux KVM.
+    Kvm,
+    ///QEMU without hardware acceleration.
+    Qemu,
+    ///VMware.
+    Vmware,
+    ///Microsoft Hyper-V.
+    HyperV,
+    ///Xen.
+    Xen,
+    ///Oracle VirtualBox.
+    VirtualBox,
+    ///AWS Nitro (EC2).
+    AwsNitro,
+    ///Hypervisor, unknown to this library.
+    Other,
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+///Container runtime, the system is running within.
+pub enum Container {
+    ///Not a container.
+    None,
+    ///Docker.
+    Docker,
+    ///Podman.
+    Podman,
+    ///LXC.
+    Lxc,
+    ///systemd-nspawn.
+    SystemdNspawn,
+    ///Kubernetes pod.
+    Kubernetes,
+    ///Windows Subsystem for Linux.
+    Wsl,
+    ///Container runtime, unknown to this library.
+    Other,
+}
+
+impl Container {
+    fn from_manager(name: &[u8]) -> Self {
+        match trim(name) {
+            b"docker" => Container::Docker,
+            b"podman" => Container::Podman,
+            b"lxc" | b"lxc-libvirt" => Container::Lxc,
+            b"systemd-nspawn" => Container::SystemdNspawn,
+            b"wsl" => Container::Wsl,
+            _ => Container::Other,
+        }
+    }
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+///Virtualization environment.
+pub struct Virtualization {
+    ///Hypervisor, if running inside virtual machine.
+    pub vm: Hypervisor,
+    ///Container runtime, if running inside container.
+    pub container: Container,
+}
+
+impl Virtualization {
+    ///Detects virtualization using only `sysfs` and `procfs` files, mounted at `root`.
+    ///
+    ///As CPUID is not consulted, hypervisors not exposing themselves via DMI cannot be detected.
+    pub fn with_root(root: &str) -> Self {
+        let root = root.as_bytes();
+        Self {
+            vm: detect_vm(root, Hypervisor::None),
+            container: detect_container(root),
+        }
+    }
+
+    #[inline]
+    ///Returns whether system runs inside virtual machine.
+    pub fn is_vm(&self) -> bool {
+        self.vm != Hypervisor::None
+    }
+
+    #[inline]
+    ///Returns whether system runs inside container.
+    pub fn is_container(&self) -> bool {
+        self.container != Container::None
+    }
+}
+
+///Detects virtualization environment, in the spirit of `systemd-detect-virt`.
+///
+///Hypervisor is detected using DMI, CPUID hypervisor leaves (on x86) and Xen/device tree files.
+///Container runtime is detected using `/proc/sys/kernel/osrelease`, `/proc/1/cgroup` and
+///marker files like `/.dockerenv` and `/run/.containerenv`.
+pub fn virtualization() -> Virtualization {
+    Virtualization {
+        vm: detect_vm(b"", cpuid()),
+        container: detect_container(b""),
+    }
+}
+
+fn trim(mut value: &[u8]) -> &[u8] {
+    while let [rest @ .., last] = value {
+        match last.is_ascii_whitespace() || *last == 0 {
+            true => value = rest,
+            false => break,
+        }
+    }
+    while let [first, rest @ ..] = value {
+        match first.is_ascii_whitespace() {
+            true => value = rest,
+            false => break,
+        }
+    }
+    value
+}
+
+#[inline]
+fn contains(haystack: &[u8], needle: &[u8]) -> bool {
+    haystack.windows(needle.len()).any(|window| window == needle)
+}
+
+#[cfg(any(target_arch = "x86", target_arch = "x86_64"))]
+#[allow(unused_unsafe)]
+fn cpuid() -> Hypervisor {
+    #[cfg(target_arch = "x86")]
+    use core::arch::x86::__cpuid;
+    #[cfg(target_arch = "x86_64")]
+    use core::arch::x86_64::__cpuid;
+
+    const HYPERVISOR_BIT: u32 = 1 << 31;
+
+    let features = unsafe {
+        __cpuid(1)
+    };
+    if features.ecx & HYPERVISOR_BIT == 0 {
+        return Hypervisor::None;
+    }
+
+    let leaf = unsafe {
+        __cpuid(0x4000_0000)
+    };
+    let mut vendor = [0u8; 12];
+    vendor[..4].copy_from_slice(&leaf.ebx.to_le_bytes());
+    vendor[4..8].copy_from_slice(&leaf.ecx.to_le_bytes());
+    vendor[8..].copy_from_slice(&leaf.edx.to_le_bytes());
+
+    match &vendor {
+        b"KVMKVMKVM\0\0\0" => Hypervisor::Kvm,
+        b"TCGTCGTCGTCG" => Hypervisor::Qemu,
+        b"VMwareVMware" => Hypervisor::Vmware,
+        b"Microsoft Hv" => Hypervisor::HyperV,
+        b"XenVMMXenVMM" => Hypervisor::Xen,
+        b"VBoxVBoxVBox" => Hypervisor::VirtualBox,
+        _ => Hypervisor::Other,
+    }
+}
+
+#[cfg(not(any(target_arch = "x86", target_arch = "x86_64")))]
+#[inline(always)]
+fn cpuid() -> Hypervisor {
+    Hypervisor::None
+}
+
+fn detect_dmi(root: &[u8]) -> Hypervisor {
+    for file in DMI_FILES.iter() {
+        let content = match fs::read(&[root, file]) {
+            Some(content) => content,
+            None => continue,
+        };
+        let content = trim(&content);
+
+        for (vendor, hypervisor) in DMI_VENDORS.iter() {
+            if content.starts_with(vendor) {
+                return *hypervisor;
+            }
+        }
+    }
+
+    //Hyper-V identifies itself only as generic Microsoft virtual machine
+    match fs::read(&[root, b"/sys/class/dmi/id/sys_vendor"]) {
+        Some(vendor) if trim(&vendor) == b"Microsoft Corporation" => match fs::read(&[root, b"/sys/class/dmi/id/product_name"]) {
+            Some(product) if trim(&product) == b"Virtual Machine" => Hypervisor::HyperV,
+            _ => Hypervisor::None,
+        },
+        _ => Hypervisor::None,
+    }
+}
+
+fn detect_xen(root: &[u8]) -> bool {
+    if let Some(kind) = fs::read(&[root, b"/sys/hypervisor/type"]) {
+        return trim(&kind) == b"xen";
+    }
+
+    //Dom0 exposes control interface, while it has no DMI entries
+    fs::exists(&[root, b"/proc/xen"])
+}
+
+fn detect_device_tree(root: &[u8]) -> Hypervisor {
+    let compatible = match fs::read(&[root, b"/proc/device-tree/hypervisor/compatible"]) {
+        Some(compatible) => compatible,
+        None => return Hypervisor::None,
+    };
+
+    //List of null separated strings
+    for entry in compatible.split(|byte| *byte == 0) {
+        match entry {
+            b"linux,kvm" => return Hypervisor::Kvm,
+            b"xen" => return Hypervisor::Xen,
+            b"vmware" => return Hypervisor::Vmware,
+            _ => (),
+        }
+    }
+
+    Hypervisor::Other
+}
+
+fn has_hypervisor_flag(root: &[u8]) -> bool {
+    let content = match fs::read(&[root, b"/proc/cpuinfo"]) {
+        Some(content) => content,
+        None => return false,
+    };
+
+    content.split(|byte| *byte == b'\n')
+           .filter(|line| line.starts_with(b"flags"))
+           .any(|line| line.split(|byte| byte.is_ascii_whitespace()).any(|flag| flag == b"hypervisor"))
+}
+
+fn detect_vm(root: &[u8], cpuid: Hypervisor) -> Hypervisor {
+    let dmi = detect_dmi(root);
+    //These hypervisors are known to report other's CPUID signature
+    match dmi {
+        Hypervisor::AwsNitro | Hypervisor::VirtualBox | Hypervisor::Xen => return dmi,
+        _ => (),
+    }
+
+    if cpuid != Hypervisor::None {
+        return cpuid;
+    }
+    if dmi != Hypervisor::None {
+        return dmi;
+    }
+    if detect_xen(root) {
+        return Hypervisor::Xen;
+    }
+
+    match detect_device_tree(root) {
+        Hypervisor::None if has_hypervisor_flag(root) => Hypervisor::Other,
+        result => result,
+    }
+}
+
+fn detect_cgroup(cgroup: &[u8]) -> Container {
+    if contains(cgroup, b"kubepods") {
+        Container::Kubernetes
+    } else if contains(cgroup, b"libpod") {
+        Container::Podman
+    } else if contains(cgroup, b"/docker") || contains(cgroup, b"docker-") {
+        Container::Docker
+    } else if contains(cgroup, b"/lxc") || contains(cgroup, b"lxc.payload") {
+        Container::Lxc
+    } else {
+        Container::None
+    }
+}
+
+fn detect_container(root: &[u8]) -> Container {
+    if let Some(release) = fs::read(&[root, b"/proc/sys/kernel/osrelease"]) {
+        if contains(&release, b"Microsoft") || contains(&release, b"WSL") {
+            return Container::Wsl;
+        }
+    }
+
+    let cgroup = match fs::read(&[root, b"/proc/1/cgroup"]) {
+        Some(cgroup) => detect_cgroup(&cgroup),
+        None => Container::None,
+    };
+    //Pod's containers are indistinguishable from plain runtime's ones otherwise
+    if cgroup == Container::Kubernetes {
+        return cgroup;
+    }
+
+    //Set by systemd and most container managers
+    for path in [b"/run/systemd/container" as &[u8], b"/run/host/container-manager"].iter() {
+        if let Some(name) = fs::read(&[root, path]) {
+            return Container::from_manager(&name);
+        }
+    }
+
+    //Usually readable only by root
+    if let Some(environ) = fs::read(&[root, b"/proc/1/environ"]) {
+        if let Some(name) = environ.split(|byte| *byte == 0).find_map(|var| var.strip_prefix(b"container=")) {
+            return Container::from_manager(name);
+        }
+    }
+
+    if fs::exists(&[root, b"/run/.containerenv"]) {
+        return Container::Podman;
+    }
+    if fs::exists(&[root, b"/.dockerenv"]) {
+        return Container::Docker;
+    }
+    if cgroup != Container::None {
+        return cgroup;
+    }
+
+    //OpenVZ exposes /proc/vz in containers, but /proc/bc only on host
+    if fs::exists(&[root, b"/proc/vz"]) && !fs::exists(&[root, b"/proc/bc"]) {
+        return Container::Other;
+    }
+
+    Container::None
+}
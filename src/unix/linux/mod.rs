@@ -1,3 +1,7 @@
+pub(crate) mod fs;
+pub(crate) mod cgroup;
 pub mod network;
 pub mod cpu;
+pub mod host;
+pub mod numa;
 pub use super::posix::mem;
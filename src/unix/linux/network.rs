@@ -4,33 +4,86 @@ extern crate alloc;
 
 use alloc::vec::Vec;
 
-use core::mem;
+use core::{cmp, mem, ptr};
 
-pub use crate::data::network::{Ip, Address};
-pub use crate::unix::posix::network::{Interfaces, InterfacesIter, Interface, Addresses};
+pub use crate::unix::posix::network::{Ip, Address, Change, Interfaces, InterfacesIter, Interface, Addresses};
 use crate::unix::posix::network::{slice_c_str, InterfaceData};
 
+pub mod sysctl;
+mod stats;
+pub use stats::{stats, Stats, Conntrack, SockStat, TcpSockets, Snmp, IpCounters, TcpCounters, UdpCounters, Netstat};
+mod bridge;
+pub use bridge::{bridge_fdb, FdbEntry};
+mod bond;
+pub use bond::{bond_info, BondInfo, BondMode, BondSlave, MiiStatus};
+mod wireless;
+pub use wireless::{WirelessInfo, WirelessMode, LinkQuality};
+
 const ALIGN_SIZE: usize = 4;
 const NETLINK_HEADER_SIZE: usize = mem::size_of::<libc::nlmsghdr>();
-const NETLINK_ADDR_REQ_SIZE: usize = mem::size_of::<NetlinkAddrReq>();
+const RTA_HEADER_SIZE: usize = mem::size_of::<RtaAttr>();
+//NLA_F_NESTED | NLA_F_NET_BYTEORDER
+const RTA_TYPE_MASK: u16 = !(0x8000 | 0x4000);
+
+#[inline(always)]
+const fn align(size: usize) -> usize {
+    (size + ALIGN_SIZE - 1) & !(ALIGN_SIZE - 1)
+}
 
 #[repr(C)]
 #[repr(align(4))]
+#[derive(Clone, Copy)]
 struct RtaAttr {
     rta_len: u16,
     rta_type: u16,
 }
 
-impl RtaAttr {
+///Iterator over routing attributes, yielding attribute's type and its payload.
+pub(crate) struct RtaAttrs<'a> {
+    data: &'a [u8],
+}
+
+impl<'a> RtaAttrs<'a> {
     #[inline(always)]
-    fn is_ok(&self, payload_len: u32) -> bool {
-        payload_len >= mem::size_of::<Self>() as u32 &&
-        self.rta_len as usize >= mem::size_of::<RtaAttr>() &&
-        self.rta_len as u32 <= payload_len
+    pub(crate) fn new(data: &'a [u8]) -> Self {
+        Self {
+            data
+        }
+    }
+}
+
+impl<'a> Iterator for RtaAttrs<'a> {
+    type Item = (u16, &'a [u8]);
+
+    fn next(&mut self) -> Option<Self::Item> {
+        let rta_attr = read_struct::<RtaAttr>(self.data)?;
+        let rta_len = rta_attr.rta_len as usize;
+        if rta_len < RTA_HEADER_SIZE || rta_len > self.data.len() {
+            self.data = &[];
+            return None;
+        }
+
+        let payload = &self.data[RTA_HEADER_SIZE..rta_len];
+        //go to next RTA
+        self.data = &self.data[cmp::min(align(rta_len), self.data.len())..];
+        Some((rta_attr.rta_type & RTA_TYPE_MASK, payload))
+    }
+}
+
+#[inline]
+///Reads plain struct from the beginning of the buffer.
+pub(crate) fn read_struct<T: Copy>(data: &[u8]) -> Option<T> {
+    if data.len() < mem::size_of::<T>() {
+        None
+    } else {
+        Some(unsafe {
+            ptr::read_unaligned(data.as_ptr() as *const T)
+        })
     }
 }
 
 #[repr(C)]
+#[derive(Clone, Copy)]
 struct IfAddrMsg {
     ifa_family: u8,
     ifa_prefixlen: u8,
@@ -41,28 +94,36 @@ struct IfAddrMsg {
 
 #[repr(C)]
 #[repr(align(4))]
-struct NetlinkAddrReq {
+///Netlink request, consisting of header and message.
+pub(crate) struct NetlinkReq<T> {
     header: libc::nlmsghdr,
-    msg: IfAddrMsg,
+    msg: T,
 }
 
-unsafe fn extract_rta_data<T: Copy>(rta_attr: &RtaAttr) -> T {
-    let mut out = mem::MaybeUninit::<T>::zeroed();
-
-    let rta_data = (rta_attr as *const _ as *const u8).add(mem::size_of_val(rta_attr)) as *const u8;
-    let rta_len = (rta_attr.rta_len as usize) - mem::size_of_val(rta_attr);
+impl<T: Copy> NetlinkReq<T> {
+    ///Creates dump request for specified message type.
+    pub(crate) fn dump(nlmsg_type: u16, msg: T) -> Self {
+        let mut header = unsafe {
+            mem::MaybeUninit::<libc::nlmsghdr>::zeroed().assume_init()
+        };
+        header.nlmsg_flags = (libc::NLM_F_REQUEST | libc::NLM_F_DUMP) as u16;
+        header.nlmsg_type = nlmsg_type;
+        header.nlmsg_len = mem::size_of::<Self>() as u32;
 
-    (out.as_mut_ptr() as *mut u8).copy_from_nonoverlapping(rta_data, rta_len as usize);
-    out.assume_init()
+        Self {
+            header,
+            msg,
+        }
+    }
 }
 
-struct Socket {
+pub(crate) struct Socket {
     fd: libc::c_int,
     addr: libc::sockaddr_nl,
 }
 
 impl Socket {
-    fn new() -> Option<Self> {
+    pub(crate) fn new() -> Option<Self> {
         let mut addr = unsafe {
             mem::MaybeUninit::<libc::sockaddr_nl>::zeroed().assume_init()
         };
@@ -74,10 +135,10 @@ impl Socket {
         })
     }
 
-    fn send(&self, mut msg: NetlinkAddrReq) -> bool {
+    pub(crate) fn send<T>(&self, msg: &mut NetlinkReq<T>) -> bool {
         let mut msg = libc::iovec {
-            iov_base: &mut msg as *mut _ as *mut _,
             iov_len: msg.header.nlmsg_len as _,
+            iov_base: msg as *mut _ as *mut _,
         };
         let mut req = unsafe {
             mem::MaybeUninit::<libc::msghdr>::zeroed().assume_init()
@@ -117,6 +178,42 @@ impl Socket {
         }
 
     }
+
+    ///Sends dump request and reads all responses until `NLMSG_DONE`.
+    ///
+    ///Callback receives type of each message and its payload, aborting dump when it returns `None`.
+    pub(crate) fn dump<T>(&self, mut req: NetlinkReq<T>, mut cb: impl FnMut(u16, &[u8]) -> Option<()>) -> Option<()> {
+        const DONE: u16 = libc::NLMSG_DONE as u16;
+        const ERROR: u16 = libc::NLMSG_ERROR as u16;
+
+        if !self.send(&mut req) {
+            return None;
+        }
+
+        let mut buf = [0u8; 65536];
+        while let Some(size) = self.recv(&mut buf) {
+            let mut cursor = &buf[..size];
+
+            while let Some(header) = read_struct::<libc::nlmsghdr>(cursor) {
+                let msg_len = header.nlmsg_len as usize;
+                if msg_len < NETLINK_HEADER_SIZE || msg_len > cursor.len() {
+                    break;
+                }
+
+                match header.nlmsg_type {
+                    DONE => return Some(()),
+                    ERROR => return None,
+                    msg_type => cb(msg_type, &cursor[NETLINK_HEADER_SIZE..msg_len])?,
+                }
+
+                //Go to next message
+                cursor = &cursor[cmp::min(align(msg_len), cursor.len())..];
+            }
+        }
+
+        //Failed to read from socket
+        None
+    }
 }
 
 impl Drop for Socket {
@@ -140,6 +237,17 @@ fn socket() -> Option<libc::c_int> {
     Some(fd)
 }
 
+impl<'a> Interface<'a> {
+    #[inline]
+    ///Returns interface's sysctl configuration.
+    ///
+    ///Returns `None` if name is not valid utf-8 or configuration is not available.
+    pub fn sysctl(&self) -> Option<sysctl::InterfaceConf> {
+        let name = core::str::from_utf8(self.data.name()).ok()?;
+        sysctl::InterfaceConf::get(name)
+    }
+}
+
 impl Interfaces {
     #[inline(always)]
     //It can fail if interface_index is invalid
@@ -176,95 +284,82 @@ impl Interfaces {
     ///
     ///In case of failure please check `std::io::Error::last_os_error()`
     pub fn new() -> Option<Self> {
-        let netlink = Socket::new()?;
-        let mut req = unsafe {
-            mem::MaybeUninit::<NetlinkAddrReq>::zeroed().assume_init()
-        };
-        req.header.nlmsg_flags = (libc::NLM_F_REQUEST | libc::NLM_F_DUMP) as u16;
-        req.header.nlmsg_type = 22; //RTM_GETADDR
-        req.header.nlmsg_len = NETLINK_ADDR_REQ_SIZE as u32;
-        req.msg.ifa_family = libc::AF_UNSPEC as _; //All IPs
-        req.msg.ifa_index = 0; //All interfaces
+        const NEW_ADDR: u16 = 20; //RTM_NEWADDR
+        const GET_ADDR: u16 = 22; //RTM_GETADDR
 
-        if !netlink.send(req) {
-            return None;
-        }
+        let netlink = Socket::new()?;
+        let req = NetlinkReq::dump(GET_ADDR, IfAddrMsg {
+            ifa_family: libc::AF_UNSPEC as _, //All IPs
+            ifa_prefixlen: 0,
+            ifa_flags: 0,
+            ifa_scope: 0,
+            ifa_index: 0, //All interfaces
+        });
 
         let mut result = Interfaces {
             inner: Vec::new()
         };
-        let mut buf = [0u8; 65536];
-        while let Some(mut size) = netlink.recv(&mut buf) {
-            let mut cursor_ptr = buf.as_ptr();
-            let mut cursor = unsafe {
-                &*(cursor_ptr as *const NetlinkAddrReq)
-            };
-
-            const DONE: u16 = libc::NLMSG_DONE as u16;
-            const ERROR: u16 = libc::NLMSG_ERROR as u16;
-            const NEW_ADDR: u16 = 20; //RTM_NEWADDR
-
-            while size >= NETLINK_HEADER_SIZE && cursor.header.nlmsg_len >= NETLINK_HEADER_SIZE as u32 && cursor.header.nlmsg_len <= size as u32 {
-                match cursor.header.nlmsg_type {
-                    DONE => return Some(result),
-                    ERROR => return None,
-                    NEW_ADDR => unsafe {
-                        let if_req = &cursor.msg;
-                        let mut data_len = cursor.header.nlmsg_len - mem::size_of_val(cursor) as u32;
-                        let rta_attr = cursor_ptr.add(mem::size_of_val(cursor)) as *const RtaAttr;
-                        let mut rta_attr = &*rta_attr;
-
-                        while rta_attr.is_ok(data_len) {
-                            if rta_attr.rta_type == 2  {
-                                //IFA_LOCAL
-                                //RTM_GETADDR only responds with ipv4
-                                if if_req.ifa_family == libc::AF_INET as u8 {
-                                    let interface = result.store_interface(if_req.ifa_index)?;
-
-                                    let ip = extract_rta_data::<[u8; mem::size_of::<u32>()]>(rta_attr);
-                                    let ip = Ip::V4(ip);
-
-                                    interface.push(Address {
-                                        ip,
-                                        prefix: if_req.ifa_prefixlen,
-                                    });
-                                }
-                            } else if rta_attr.rta_type == 1  {
-                                //IFA_ADDRESS
-                                //RTM_GETADDR responds with ipv6
-                                if if_req.ifa_family == libc::AF_INET6 as u8 {
-                                    let interface = result.store_interface(if_req.ifa_index)?;
-                                    let ip = extract_rta_data::<[u16; 8]>(rta_attr);
-                                    let ip = Ip::V6(ip);
-
-                                    interface.push(Address {
-                                        ip,
-                                        prefix: if_req.ifa_prefixlen,
-                                    });
-                                }
-                            }
-
-                            //go to next RTA
-                            let rta_size = (rta_attr.rta_len as usize + ALIGN_SIZE - 1) & !(ALIGN_SIZE - 1); //aligned
-                            data_len -= rta_size as u32;
-                            rta_attr = &*((rta_attr as *const _ as *const u8).add(rta_size) as *const RtaAttr);
-                        }
-                    },
-                    //we don't care about anything else
-                    _ => (),
-                }
 
-                //Go to next message
-                let msg_size = (cursor.header.nlmsg_len as usize + ALIGN_SIZE - 1) & !(ALIGN_SIZE - 1); //aligned
-                size -= msg_size;
-                unsafe {
-                    cursor_ptr = cursor_ptr.add(msg_size);
-                    cursor = &*(cursor_ptr as *const NetlinkAddrReq);
+        netlink.dump(req, |msg_type, payload| {
+            //we don't care about anything else
+            if msg_type != NEW_ADDR {
+                return Some(());
+            }
+
+            let if_req = read_struct::<IfAddrMsg>(payload)?;
+            let attrs = payload.get(align(mem::size_of::<IfAddrMsg>())..).unwrap_or(&[]);
+            for (rta_type, rta_data) in RtaAttrs::new(attrs) {
+                if rta_type == 2  {
+                    //IFA_LOCAL
+                    //RTM_GETADDR only responds with ipv4
+                    if if_req.ifa_family == libc::AF_INET as u8 {
+                        let interface = result.store_interface(if_req.ifa_index)?;
+
+                        let ip = read_struct::<[u8; mem::size_of::<u32>()]>(rta_data)?;
+                        let ip = Ip::V4(ip);
+
+                        interface.push(Address {
+                            ip,
+                            prefix: if_req.ifa_prefixlen,
+                        });
+                    }
+                } else if rta_type == 1  {
+                    //IFA_ADDRESS
+                    //RTM_GETADDR responds with ipv6
+                    if if_req.ifa_family == libc::AF_INET6 as u8 {
+                        let interface = result.store_interface(if_req.ifa_index)?;
+                        let ip = read_struct::<[u16; 8]>(rta_data)?;
+                        let ip = Ip::V6(ip);
+
+                        interface.push(Address {
+                            ip,
+                            prefix: if_req.ifa_prefixlen,
+                        });
+                    }
                 }
             }
-        }
 
-        //Failed to read from socket
-        None
+            Some(())
+        })?;
+
+        Some(result)
+    }
+}
+
+#[cfg(test)]
+pub(crate) mod tests {
+    extern crate alloc;
+
+    use alloc::vec::Vec;
+
+    use super::{align, RTA_HEADER_SIZE};
+
+    ///Appends routing attribute, padded to alignment.
+    pub(crate) fn push_rta(buffer: &mut Vec<u8>, rta_type: u16, data: &[u8]) {
+        let len = RTA_HEADER_SIZE + data.len();
+        buffer.extend_from_slice(&(len as u16).to_ne_bytes());
+        buffer.extend_from_slice(&rta_type.to_ne_bytes());
+        buffer.extend_from_slice(data);
+        buffer.resize(buffer.len() + align(len) - len, 0);
     }
 }
@@ -0,0 +1,382 @@
+//! Bonding interface information.
+
+extern crate alloc;
+
+use alloc::vec::Vec;
+
+use core::{cmp, mem};
+
+use super::{align, read_struct, NetlinkReq, RtaAttrs, Socket, Interface};
+use crate::unix::posix::network::slice_c_str;
+
+const RTM_NEWLINK: u16 = 16;
+const RTM_GETLINK: u16 = 18;
+
+const IFLA_IFNAME: u16 = 3;
+const IFLA_MASTER: u16 = 10;
+const IFLA_LINKINFO: u16 = 18;
+
+const IFLA_INFO_KIND: u16 = 1;
+const IFLA_INFO_DATA: u16 = 2;
+const IFLA_INFO_SLAVE_KIND: u16 = 4;
+const IFLA_INFO_SLAVE_DATA: u16 = 5;
+
+const IFLA_BOND_MODE: u16 = 1;
+const IFLA_BOND_ACTIVE_SLAVE: u16 = 2;
+const IFLA_BOND_MIIMON: u16 = 3;
+
+const IFLA_BOND_SLAVE_STATE: u16 = 1;
+const IFLA_BOND_SLAVE_MII_STATUS: u16 = 2;
+const IFLA_BOND_SLAVE_LINK_FAILURE_COUNT: u16 = 3;
+
+const BOND_KIND: &[u8] = b"bond";
+
+#[repr(C)]
+#[derive(Clone, Copy)]
+struct IfInfoMsg {
+    ifi_family: u8,
+    ifi_pad: u8,
+    ifi_type: u16,
+    ifi_index: i32,
+    ifi_flags: u32,
+    ifi_change: u32,
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+///Bonding mode.
+pub enum BondMode {
+    ///Round-robin (`balance-rr`).
+    BalanceRr,
+    ///Single active slave (`active-backup`).
+    ActiveBackup,
+    ///XOR hash policy (`balance-xor`).
+    BalanceXor,
+    ///Transmit on all slaves (`broadcast`).
+    Broadcast,
+    ///IEEE 802.3ad dynamic link aggregation (`802.3ad`).
+    Lacp,
+    ///Adaptive transmit load balancing (`balance-tlb`).
+    BalanceTlb,
+    ///Adaptive load balancing (`balance-alb`).
+    BalanceAlb,
+    ///Mode, unknown to this library.
+    Unknown(u8),
+}
+
+impl BondMode {
+    const fn from_value(value: u8) -> Self {
+        match value {
+            0 => BondMode::BalanceRr,
+            1 => BondMode::ActiveBackup,
+            2 => BondMode::BalanceXor,
+            3 => BondMode::Broadcast,
+            4 => BondMode::Lacp,
+            5 => BondMode::BalanceTlb,
+            6 => BondMode::BalanceAlb,
+            value => BondMode::Unknown(value),
+        }
+    }
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+///Slave's MII link status.
+pub enum MiiStatus {
+    ///Link is up.
+    Up,
+    ///Link is failing, but not yet considered down.
+    Fail,
+    ///Link is down.
+    Down,
+    ///Link is recovering, but not yet considered up.
+    Back,
+    ///Status, unknown to this library.
+    Unknown(u8),
+}
+
+impl MiiStatus {
+    const fn from_value(value: u8) -> Self {
+        match value {
+            0 => MiiStatus::Up,
+            1 => MiiStatus::Fail,
+            2 => MiiStatus::Down,
+            3 => MiiStatus::Back,
+            value => MiiStatus::Unknown(value),
+        }
+    }
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+///Bond's slave.
+pub struct BondSlave {
+    ///Interface index.
+    pub index: u32,
+    name: [u8; libc::IFNAMSIZ],
+    ///Whether slave is active one, as opposite to backup.
+    pub is_active: bool,
+    ///MII link status.
+    pub mii_status: MiiStatus,
+    ///Number of link failures.
+    pub link_failure_count: u32,
+}
+
+impl BondSlave {
+    #[inline]
+    ///Returns name of the slave interface, if available as utf-8 string.
+    pub fn name(&self) -> Option<&str> {
+        core::str::from_utf8(slice_c_str(&self.name)).ok()
+    }
+}
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+///Bonding interface information.
+pub struct BondInfo {
+    ///Bonding mode.
+    pub mode: BondMode,
+    ///Index of active slave, if any.
+    pub active_slave: Option<u32>,
+    ///MII link monitoring interval in milliseconds, 0 if disabled.
+    pub miimon: u32,
+    ///List of slaves.
+    pub slaves: Vec<BondSlave>,
+}
+
+fn parse_bond_data(data: &[u8], info: &mut BondInfo) {
+    for (rta_type, rta_data) in RtaAttrs::new(data) {
+        match rta_type {
+            IFLA_BOND_MODE => if let Some(mode) = read_struct(rta_data) {
+                info.mode = BondMode::from_value(mode);
+            },
+            IFLA_BOND_ACTIVE_SLAVE => info.active_slave = read_struct::<u32>(rta_data).filter(|index| *index != 0),
+            IFLA_BOND_MIIMON => info.miimon = read_struct(rta_data).unwrap_or(0),
+            _ => (),
+        }
+    }
+}
+
+fn parse_slave_data(data: &[u8], slave: &mut BondSlave) {
+    for (rta_type, rta_data) in RtaAttrs::new(data) {
+        match rta_type {
+            IFLA_BOND_SLAVE_STATE => slave.is_active = read_struct::<u8>(rta_data) == Some(0),
+            IFLA_BOND_SLAVE_MII_STATUS => if let Some(status) = read_struct(rta_data) {
+                slave.mii_status = MiiStatus::from_value(status);
+            },
+            IFLA_BOND_SLAVE_LINK_FAILURE_COUNT => slave.link_failure_count = read_struct(rta_data).unwrap_or(0),
+            _ => (),
+        }
+    }
+}
+
+///Collects bond information out of `RTM_NEWLINK` messages.
+struct BondParser {
+    ifindex: u32,
+    is_bond: bool,
+    info: BondInfo,
+}
+
+impl BondParser {
+    fn new(ifindex: u32) -> Self {
+        Self {
+            ifindex,
+            is_bond: false,
+            info: BondInfo {
+                mode: BondMode::BalanceRr,
+                active_slave: None,
+                miimon: 0,
+                slaves: Vec::new(),
+            },
+        }
+    }
+
+    ///Parses link message, taking bond itself and its slaves into account.
+    fn parse_link(&mut self, payload: &[u8]) -> Option<()> {
+        let msg = read_struct::<IfInfoMsg>(payload)?;
+        let attrs = payload.get(align(mem::size_of::<IfInfoMsg>())..).unwrap_or(&[]);
+        let index = msg.ifi_index as u32;
+
+        let mut name = [0u8; libc::IFNAMSIZ];
+        let mut master = None;
+        let mut link_info = None;
+        for (rta_type, rta_data) in RtaAttrs::new(attrs) {
+            match rta_type {
+                IFLA_IFNAME => {
+                    let len = cmp::min(name.len(), rta_data.len());
+                    name[..len].copy_from_slice(&rta_data[..len]);
+                },
+                IFLA_MASTER => master = read_struct::<u32>(rta_data),
+                IFLA_LINKINFO => link_info = Some(rta_data),
+                _ => (),
+            }
+        }
+
+        let link_info = link_info?;
+
+        if index == self.ifindex {
+            let mut info_data = None;
+            for (rta_type, rta_data) in RtaAttrs::new(link_info) {
+                match rta_type {
+                    IFLA_INFO_KIND => self.is_bond = rta_data.split(|byte| *byte == 0).next() == Some(BOND_KIND),
+                    IFLA_INFO_DATA => info_data = Some(rta_data),
+                    _ => (),
+                }
+            }
+
+            if let (true, Some(info_data)) = (self.is_bond, info_data) {
+                parse_bond_data(info_data, &mut self.info);
+            }
+        } else if master == Some(self.ifindex) {
+            let mut slave = BondSlave {
+                index,
+                name,
+                is_active: false,
+                mii_status: MiiStatus::Unknown(u8::MAX),
+                link_failure_count: 0,
+            };
+
+            let mut is_bond_slave = false;
+            for (rta_type, rta_data) in RtaAttrs::new(link_info) {
+                match rta_type {
+                    IFLA_INFO_SLAVE_KIND => is_bond_slave = rta_data.split(|byte| *byte == 0).next() == Some(BOND_KIND),
+                    IFLA_INFO_SLAVE_DATA => parse_slave_data(rta_data, &mut slave),
+                    _ => (),
+                }
+            }
+
+            if is_bond_slave {
+                self.info.slaves.push(slave);
+            }
+        }
+
+        Some(())
+    }
+
+    #[inline]
+    fn finish(self) -> Option<BondInfo> {
+        match self.is_bond {
+            true => Some(self.info),
+            false => None,
+        }
+    }
+}
+
+///Retrieves bond information of the interface with specified index.
+///
+///Returns `None` if interface is not a bond or request fails.
+pub fn bond_info(ifindex: u32) -> Option<BondInfo> {
+    let netlink = Socket::new()?;
+    let req = NetlinkReq::dump(RTM_GETLINK, IfInfoMsg {
+        ifi_family: libc::AF_UNSPEC as _,
+        ifi_pad: 0,
+        ifi_type: 0,
+        ifi_index: 0,
+        ifi_flags: 0,
+        ifi_change: 0,
+    });
+
+    let mut parser = BondParser::new(ifindex);
+    netlink.dump(req, |msg_type, payload| {
+        if msg_type == RTM_NEWLINK {
+            //Links without link info are not of interest
+            let _ = parser.parse_link(payload);
+        }
+
+        Some(())
+    })?;
+
+    parser.finish()
+}
+
+impl<'a> Interface<'a> {
+    #[inline]
+    ///Returns bonding information, if interface is a bond.
+    pub fn bond_info(&self) -> Option<BondInfo> {
+        bond_info(self.index()?)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    extern crate alloc;
+
+    use alloc::vec::Vec;
+
+    use super::*;
+    use crate::unix::linux::network::tests::push_rta;
+
+    const IFLA_INFO_NESTED: u16 = 0x8000;
+
+    fn link(index: i32, name: &[u8], master: Option<u32>, link_info: &[u8]) -> Vec<u8> {
+        let mut result = Vec::new();
+        result.extend_from_slice(&[libc::AF_UNSPEC as u8, 0]);
+        result.extend_from_slice(&0u16.to_ne_bytes());
+        result.extend_from_slice(&index.to_ne_bytes());
+        result.extend_from_slice(&0u32.to_ne_bytes());
+        result.extend_from_slice(&0u32.to_ne_bytes());
+        push_rta(&mut result, IFLA_IFNAME, name);
+        if let Some(master) = master {
+            push_rta(&mut result, IFLA_MASTER, &master.to_ne_bytes());
+        }
+        push_rta(&mut result, IFLA_LINKINFO | IFLA_INFO_NESTED, link_info);
+        result
+    }
+
+    #[test]
+    fn should_parse_bond_links() {
+        let mut bond_data = Vec::new();
+        push_rta(&mut bond_data, IFLA_BOND_MODE, &[1]);
+        push_rta(&mut bond_data, IFLA_BOND_ACTIVE_SLAVE, &5u32.to_ne_bytes());
+        push_rta(&mut bond_data, IFLA_BOND_MIIMON, &100u32.to_ne_bytes());
+        let mut bond_info = Vec::new();
+        push_rta(&mut bond_info, IFLA_INFO_KIND, b"bond\0");
+        push_rta(&mut bond_info, IFLA_INFO_DATA | IFLA_INFO_NESTED, &bond_data);
+
+        let mut active_data = Vec::new();
+        push_rta(&mut active_data, IFLA_BOND_SLAVE_STATE, &[0]);
+        push_rta(&mut active_data, IFLA_BOND_SLAVE_MII_STATUS, &[0]);
+        push_rta(&mut active_data, IFLA_BOND_SLAVE_LINK_FAILURE_COUNT, &2u32.to_ne_bytes());
+        let mut active_info = Vec::new();
+        push_rta(&mut active_info, IFLA_INFO_KIND, b"dummy\0");
+        push_rta(&mut active_info, IFLA_INFO_SLAVE_KIND, b"bond\0");
+        push_rta(&mut active_info, IFLA_INFO_SLAVE_DATA | IFLA_INFO_NESTED, &active_data);
+
+        let mut backup_data = Vec::new();
+        push_rta(&mut backup_data, IFLA_BOND_SLAVE_STATE, &[1]);
+        push_rta(&mut backup_data, IFLA_BOND_SLAVE_MII_STATUS, &[2]);
+        let mut backup_info = Vec::new();
+        push_rta(&mut backup_info, IFLA_INFO_SLAVE_KIND, b"bond\0");
+        push_rta(&mut backup_info, IFLA_INFO_SLAVE_DATA | IFLA_INFO_NESTED, &backup_data);
+
+        //Port of a bridge, enslaved to other master
+        let mut bridge_port_info = Vec::new();
+        push_rta(&mut bridge_port_info, IFLA_INFO_SLAVE_KIND, b"bridge\0");
+
+        let mut parser = BondParser::new(4);
+        assert!(parser.parse_link(&link(4, b"bond0\0", None, &bond_info)).is_some());
+        assert!(parser.parse_link(&link(5, b"eth0\0", Some(4), &active_info)).is_some());
+        assert!(parser.parse_link(&link(6, b"eth1\0", Some(4), &backup_info)).is_some());
+        assert!(parser.parse_link(&link(7, b"eth2\0", Some(8), &bridge_port_info)).is_some());
+        assert!(parser.parse_link(&[0; 4]).is_none());
+
+        let info = parser.finish().expect("bond info");
+        assert_eq!(info.mode, BondMode::ActiveBackup);
+        assert_eq!(info.active_slave, Some(5));
+        assert_eq!(info.miimon, 100);
+        assert_eq!(info.slaves.len(), 2);
+
+        assert_eq!(info.slaves[0].index, 5);
+        assert_eq!(info.slaves[0].name(), Some("eth0"));
+        assert!(info.slaves[0].is_active);
+        assert_eq!(info.slaves[0].mii_status, MiiStatus::Up);
+        assert_eq!(info.slaves[0].link_failure_count, 2);
+
+        assert_eq!(info.slaves[1].name(), Some("eth1"));
+        assert!(!info.slaves[1].is_active);
+        assert_eq!(info.slaves[1].mii_status, MiiStatus::Down);
+        assert_eq!(info.slaves[1].link_failure_count, 0);
+
+        let mut dummy_info = Vec::new();
+        push_rta(&mut dummy_info, IFLA_INFO_KIND, b"dummy\0");
+        let mut parser = BondParser::new(5);
+        assert!(parser.parse_link(&link(5, b"eth0\0", Some(4), &dummy_info)).is_some());
+        assert!(parser.finish().is_none());
+    }
+}
@@ -0,0 +1,202 @@
+//! Bridge forwarding database.
+
+extern crate alloc;
+
+use alloc::vec::Vec;
+
+use core::mem;
+
+use super::{align, read_struct, NetlinkReq, RtaAttrs, Socket};
+
+const RTM_NEWNEIGH: u16 = 28;
+const RTM_GETNEIGH: u16 = 30;
+
+const NDA_LLADDR: u16 = 2;
+const NDA_VLAN: u16 = 5;
+const NDA_MASTER: u16 = 9;
+
+const NUD_NOARP: u16 = 0x40;
+const NUD_PERMANENT: u16 = 0x80;
+
+const NTF_SELF: u8 = 0x02;
+const NTF_MASTER: u8 = 0x04;
+const NTF_EXT_LEARNED: u8 = 0x10;
+
+#[repr(C)]
+#[derive(Clone, Copy)]
+struct NdMsg {
+    ndm_family: u8,
+    ndm_pad1: u8,
+    ndm_pad2: u16,
+    ndm_ifindex: i32,
+    ndm_state: u16,
+    ndm_flags: u8,
+    ndm_type: u8,
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+///Entry of bridge forwarding database.
+pub struct FdbEntry {
+    ///Link layer address.
+    pub mac: [u8; 6],
+    ///Index of the port interface.
+    pub port: u32,
+    ///Index of the bridge, the port belongs to.
+    pub master: Option<u32>,
+    ///VLAN id, if entry is VLAN specific.
+    pub vlan: Option<u16>,
+    ///Neighbour state flags (`NUD_*`).
+    pub state: u16,
+    ///Neighbour flags (`NTF_*`).
+    pub flags: u8,
+}
+
+impl FdbEntry {
+    #[inline(always)]
+    ///Returns whether entry is permanent (i.e. local address of the bridge).
+    pub const fn is_permanent(&self) -> bool {
+        self.state & NUD_PERMANENT != 0
+    }
+
+    #[inline(always)]
+    ///Returns whether entry is static, i.e. added by user and never expires.
+    pub const fn is_static(&self) -> bool {
+        self.state & NUD_NOARP != 0
+    }
+
+    #[inline(always)]
+    ///Returns whether entry belongs to the device's own database.
+    pub const fn is_self(&self) -> bool {
+        self.flags & NTF_SELF != 0
+    }
+
+    #[inline(always)]
+    ///Returns whether entry belongs to the bridge's database.
+    pub const fn is_master(&self) -> bool {
+        self.flags & NTF_MASTER != 0
+    }
+
+    #[inline(always)]
+    ///Returns whether entry was learned externally (e.g. by switch driver).
+    pub const fn is_extern_learned(&self) -> bool {
+        self.flags & NTF_EXT_LEARNED != 0
+    }
+}
+
+///Parses `RTM_NEWNEIGH` message of bridge family into forwarding database entry.
+fn parse_fdb_entry(payload: &[u8]) -> Option<FdbEntry> {
+    let msg = read_struct::<NdMsg>(payload)?;
+    if msg.ndm_family != libc::AF_BRIDGE as u8 {
+        return None;
+    }
+
+    let mut entry = FdbEntry {
+        mac: [0; 6],
+        port: msg.ndm_ifindex as u32,
+        master: None,
+        vlan: None,
+        state: msg.ndm_state,
+        flags: msg.ndm_flags,
+    };
+
+    let attrs = payload.get(align(mem::size_of::<NdMsg>())..).unwrap_or(&[]);
+    for (rta_type, rta_data) in RtaAttrs::new(attrs) {
+        match rta_type {
+            NDA_LLADDR => if let Some(mac) = read_struct(rta_data) {
+                entry.mac = mac;
+            },
+            NDA_VLAN => entry.vlan = read_struct(rta_data),
+            NDA_MASTER => entry.master = read_struct(rta_data),
+            _ => (),
+        }
+    }
+
+    Some(entry)
+}
+
+///Dumps bridge forwarding database, limiting entries to the bridge or port with `ifindex`.
+///
+///When `ifindex` is 0, all entries are returned.
+///
+///In case of failure please check `std::io::Error::last_os_error()`
+pub fn bridge_fdb(ifindex: u32) -> Option<Vec<FdbEntry>> {
+    let netlink = Socket::new()?;
+    let req = NetlinkReq::dump(RTM_GETNEIGH, NdMsg {
+        ndm_family: libc::AF_BRIDGE as _,
+        ndm_pad1: 0,
+        ndm_pad2: 0,
+        ndm_ifindex: 0,
+        ndm_state: 0,
+        ndm_flags: 0,
+        ndm_type: 0,
+    });
+
+    let mut result = Vec::new();
+    netlink.dump(req, |msg_type, payload| {
+        if msg_type != RTM_NEWNEIGH {
+            return Some(());
+        }
+
+        if let Some(entry) = parse_fdb_entry(payload) {
+            if ifindex == 0 || entry.port == ifindex || entry.master == Some(ifindex) {
+                result.push(entry);
+            }
+        }
+
+        Some(())
+    })?;
+
+    Some(result)
+}
+
+#[cfg(test)]
+mod tests {
+    extern crate alloc;
+
+    use alloc::vec::Vec;
+
+    use super::{parse_fdb_entry, NDA_LLADDR, NDA_MASTER, NDA_VLAN, NTF_MASTER, NTF_SELF, NUD_NOARP, NUD_PERMANENT};
+    use crate::unix::linux::network::tests::push_rta;
+
+    fn nd_msg(family: u8, ifindex: i32, state: u16, flags: u8) -> Vec<u8> {
+        let mut result = Vec::new();
+        result.push(family);
+        result.extend_from_slice(&[0; 3]);
+        result.extend_from_slice(&ifindex.to_ne_bytes());
+        result.extend_from_slice(&state.to_ne_bytes());
+        result.push(flags);
+        result.push(0);
+        result
+    }
+
+    #[test]
+    fn should_parse_fdb_entries() {
+        let mut payload = nd_msg(libc::AF_BRIDGE as u8, 3, NUD_NOARP, NTF_MASTER);
+        //Address is not aligned, checking padding before following attributes
+        push_rta(&mut payload, NDA_LLADDR, &[0x02, 0x42, 0xac, 0x11, 0x00, 0x02]);
+        push_rta(&mut payload, NDA_VLAN, &10u16.to_ne_bytes());
+        push_rta(&mut payload, NDA_MASTER, &2u32.to_ne_bytes());
+
+        let entry = parse_fdb_entry(&payload).expect("parse entry");
+        assert_eq!(entry.mac, [0x02, 0x42, 0xac, 0x11, 0x00, 0x02]);
+        assert_eq!(entry.port, 3);
+        assert_eq!(entry.master, Some(2));
+        assert_eq!(entry.vlan, Some(10));
+        assert!(entry.is_static());
+        assert!(entry.is_master());
+        assert!(!entry.is_permanent());
+        assert!(!entry.is_self());
+
+        let mut payload = nd_msg(libc::AF_BRIDGE as u8, 2, NUD_PERMANENT, NTF_SELF);
+        push_rta(&mut payload, NDA_LLADDR, &[0x33, 0x33, 0, 0, 0, 1]);
+        let entry = parse_fdb_entry(&payload).expect("parse entry");
+        assert_eq!(entry.mac, [0x33, 0x33, 0, 0, 0, 1]);
+        assert_eq!(entry.master, None);
+        assert_eq!(entry.vlan, None);
+        assert!(entry.is_permanent());
+        assert!(entry.is_self());
+
+        assert!(parse_fdb_entry(&nd_msg(libc::AF_INET as u8, 2, 0, 0)).is_none());
+        assert!(parse_fdb_entry(&payload[..4]).is_none());
+    }
+}
@@ -0,0 +1,312 @@
+//! Network statistics, read from `/proc/net`.
+
+use crate::unix::linux::fs;
+
+///Splits content into lines, skipping invalid utf-8.
+fn lines(content: &[u8]) -> impl Iterator<Item = &str> {
+    content.split(|byte| *byte == b'\n').filter_map(|line| core::str::from_utf8(line).ok())
+}
+
+///Walks over `/proc/net/snmp` like tables.
+///
+///Each section consists of two lines, first with names of counters and second with its values,
+///both prefixed with section name.
+///When section name of the next line doesn't match, it is treated as header of new section.
+fn walk_table<'a>(content: &'a [u8], mut cb: impl FnMut(&'a str, &'a str, u64)) {
+    let mut header: Option<(&str, &str)> = None;
+    for line in lines(content) {
+        let (section, fields) = match line.split_once(':') {
+            Some(line) => line,
+            None => continue,
+        };
+
+        match header.take() {
+            Some((header_section, names)) if header_section == section => {
+                for (key, value) in names.split_whitespace().zip(fields.split_whitespace()) {
+                    //Negative values (e.g. `MaxConn` of -1) are not counters and are skipped
+                    if let Ok(value) = value.parse() {
+                        cb(section, key, value);
+                    }
+                }
+            },
+            _ => header = Some((section, fields)),
+        }
+    }
+}
+
+///Walks over `/proc/net/sockstat` like lines, containing section followed by name value pairs.
+fn walk_pairs<'a>(content: &'a [u8], mut cb: impl FnMut(&'a str, &'a str, u64)) {
+    for line in lines(content) {
+        let (section, pairs) = match line.split_once(':') {
+            Some(line) => line,
+            None => continue,
+        };
+
+        let mut pairs = pairs.split_whitespace();
+        while let (Some(key), Some(value)) = (pairs.next(), pairs.next()) {
+            if let Ok(value) = value.parse() {
+                cb(section, key, value);
+            }
+        }
+    }
+}
+
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq)]
+///Connection tracking table usage.
+pub struct Conntrack {
+    ///Number of currently tracked connections.
+    pub count: u64,
+    ///Maximum number of tracked connections.
+    pub max: u64,
+}
+
+impl Conntrack {
+    ///Reads conntrack usage.
+    ///
+    ///Returns `None` if `nf_conntrack` is not loaded.
+    pub fn new() -> Option<Self> {
+        Some(Self {
+            count: fs::read_value(&[b"/proc/sys/net/netfilter/nf_conntrack_count"])?,
+            max: fs::read_value(&[b"/proc/sys/net/netfilter/nf_conntrack_max"])?,
+        })
+    }
+}
+
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq)]
+///TCP sockets usage.
+pub struct TcpSockets {
+    ///Number of sockets in use.
+    pub inuse: u64,
+    ///Number of orphaned sockets.
+    pub orphan: u64,
+    ///Number of sockets in `TIME_WAIT` state.
+    pub tw: u64,
+    ///Number of allocated sockets.
+    pub alloc: u64,
+    ///Memory used, in pages.
+    pub mem: u64,
+}
+
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq)]
+///Socket summary from `/proc/net/sockstat` and `/proc/net/sockstat6`.
+pub struct SockStat {
+    ///Total number of used sockets.
+    pub used: u64,
+    ///IPv4 TCP sockets.
+    pub tcp: TcpSockets,
+    ///Number of IPv4 UDP sockets in use.
+    pub udp_inuse: u64,
+    ///Memory used by UDP sockets, in pages.
+    pub udp_mem: u64,
+    ///Number of IPv6 TCP sockets in use.
+    pub tcp6_inuse: u64,
+    ///Number of IPv6 UDP sockets in use.
+    pub udp6_inuse: u64,
+}
+
+impl SockStat {
+    ///Parses content of `sockstat` and `sockstat6` files.
+    pub fn parse(sockstat: &[u8], sockstat6: &[u8]) -> Self {
+        let mut result = Self::default();
+        let mut on_value = |section, key, value| match (section, key) {
+            ("sockets", "used") => result.used = value,
+            ("TCP", "inuse") => result.tcp.inuse = value,
+            ("TCP", "orphan") => result.tcp.orphan = value,
+            ("TCP", "tw") => result.tcp.tw = value,
+            ("TCP", "alloc") => result.tcp.alloc = value,
+            ("TCP", "mem") => result.tcp.mem = value,
+            ("UDP", "inuse") => result.udp_inuse = value,
+            ("UDP", "mem") => result.udp_mem = value,
+            ("TCP6", "inuse") => result.tcp6_inuse = value,
+            ("UDP6", "inuse") => result.udp6_inuse = value,
+            _ => (),
+        };
+
+        walk_pairs(sockstat, &mut on_value);
+        walk_pairs(sockstat6, &mut on_value);
+        result
+    }
+
+    ///Reads socket summary.
+    pub fn new() -> Option<Self> {
+        let sockstat = fs::read(&[b"/proc/net/sockstat"])?;
+        //IPv6 can be disabled
+        let sockstat6 = fs::read(&[b"/proc/net/sockstat6"]).unwrap_or_default();
+        Some(Self::parse(&sockstat, &sockstat6))
+    }
+}
+
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq)]
+///IP protocol counters.
+pub struct IpCounters {
+    ///Total number of received datagrams.
+    pub in_receives: u64,
+    ///Datagrams discarded due to header errors.
+    pub in_hdr_errors: u64,
+    ///Datagrams discarded due to invalid destination address.
+    pub in_addr_errors: u64,
+    ///Received datagrams discarded without error.
+    pub in_discards: u64,
+    ///Datagrams delivered to upper protocols.
+    pub in_delivers: u64,
+    ///Datagrams sent.
+    pub out_requests: u64,
+    ///Outgoing datagrams discarded without error.
+    pub out_discards: u64,
+    ///Datagrams discarded due to missing route.
+    pub out_no_routes: u64,
+}
+
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq)]
+///TCP protocol counters.
+pub struct TcpCounters {
+    ///Number of active connection openings.
+    pub active_opens: u64,
+    ///Number of passive connection openings.
+    pub passive_opens: u64,
+    ///Number of failed connection attempts.
+    pub attempt_fails: u64,
+    ///Number of resets of established connections.
+    pub estab_resets: u64,
+    ///Number of currently established connections.
+    pub curr_estab: u64,
+    ///Segments received.
+    pub in_segs: u64,
+    ///Segments sent.
+    pub out_segs: u64,
+    ///Segments retransmitted.
+    pub retrans_segs: u64,
+    ///Segments received with error.
+    pub in_errs: u64,
+    ///Segments sent with `RST` flag.
+    pub out_rsts: u64,
+}
+
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq)]
+///UDP protocol counters.
+pub struct UdpCounters {
+    ///Datagrams received.
+    pub in_datagrams: u64,
+    ///Datagrams received on port without listener.
+    pub no_ports: u64,
+    ///Datagrams received with error.
+    pub in_errors: u64,
+    ///Datagrams sent.
+    pub out_datagrams: u64,
+    ///Datagrams dropped due to receive buffer being full.
+    pub rcvbuf_errors: u64,
+    ///Datagrams dropped due to send buffer being full.
+    pub sndbuf_errors: u64,
+}
+
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq)]
+///Protocol counters from `/proc/net/snmp`.
+pub struct Snmp {
+    ///IP counters.
+    pub ip: IpCounters,
+    ///TCP counters.
+    pub tcp: TcpCounters,
+    ///UDP counters.
+    pub udp: UdpCounters,
+}
+
+impl Snmp {
+    ///Parses content of `/proc/net/snmp`.
+    pub fn parse(content: &[u8]) -> Self {
+        let mut result = Self::default();
+        walk_table(content, |section, key, value| match (section, key) {
+            ("Ip", "InReceives") => result.ip.in_receives = value,
+            ("Ip", "InHdrErrors") => result.ip.in_hdr_errors = value,
+            ("Ip", "InAddrErrors") => result.ip.in_addr_errors = value,
+            ("Ip", "InDiscards") => result.ip.in_discards = value,
+            ("Ip", "InDelivers") => result.ip.in_delivers = value,
+            ("Ip", "OutRequests") => result.ip.out_requests = value,
+            ("Ip", "OutDiscards") => result.ip.out_discards = value,
+            ("Ip", "OutNoRoutes") => result.ip.out_no_routes = value,
+            ("Tcp", "ActiveOpens") => result.tcp.active_opens = value,
+            ("Tcp", "PassiveOpens") => result.tcp.passive_opens = value,
+            ("Tcp", "AttemptFails") => result.tcp.attempt_fails = value,
+            ("Tcp", "EstabResets") => result.tcp.estab_resets = value,
+            ("Tcp", "CurrEstab") => result.tcp.curr_estab = value,
+            ("Tcp", "InSegs") => result.tcp.in_segs = value,
+            ("Tcp", "OutSegs") => result.tcp.out_segs = value,
+            ("Tcp", "RetransSegs") => result.tcp.retrans_segs = value,
+            ("Tcp", "InErrs") => result.tcp.in_errs = value,
+            ("Tcp", "OutRsts") => result.tcp.out_rsts = value,
+            ("Udp", "InDatagrams") => result.udp.in_datagrams = value,
+            ("Udp", "NoPorts") => result.udp.no_ports = value,
+            ("Udp", "InErrors") => result.udp.in_errors = value,
+            ("Udp", "OutDatagrams") => result.udp.out_datagrams = value,
+            ("Udp", "RcvbufErrors") => result.udp.rcvbuf_errors = value,
+            ("Udp", "SndbufErrors") => result.udp.sndbuf_errors = value,
+            _ => (),
+        });
+        result
+    }
+
+    ///Reads protocol counters.
+    pub fn new() -> Option<Self> {
+        fs::read(&[b"/proc/net/snmp"]).map(|content| Self::parse(&content))
+    }
+}
+
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq)]
+///Extended TCP counters from `/proc/net/netstat`.
+pub struct Netstat {
+    ///Number of times accept queue of listening socket overflowed.
+    pub listen_overflows: u64,
+    ///Number of connections dropped by listening socket.
+    pub listen_drops: u64,
+    ///Number of SYN cookies sent.
+    pub syncookies_sent: u64,
+    ///Number of retransmission timeouts.
+    pub tcp_timeouts: u64,
+    ///Number of packets dropped due to receive queue being full.
+    pub tcp_backlog_drop: u64,
+}
+
+impl Netstat {
+    ///Parses content of `/proc/net/netstat`.
+    pub fn parse(content: &[u8]) -> Self {
+        let mut result = Self::default();
+        walk_table(content, |section, key, value| match (section, key) {
+            ("TcpExt", "ListenOverflows") => result.listen_overflows = value,
+            ("TcpExt", "ListenDrops") => result.listen_drops = value,
+            ("TcpExt", "SyncookiesSent") => result.syncookies_sent = value,
+            ("TcpExt", "TCPTimeouts") => result.tcp_timeouts = value,
+            ("TcpExt", "TCPBacklogDrop") => result.tcp_backlog_drop = value,
+            _ => (),
+        });
+        result
+    }
+
+    ///Reads extended TCP counters.
+    pub fn new() -> Option<Self> {
+        fs::read(&[b"/proc/net/netstat"]).map(|content| Self::parse(&content))
+    }
+}
+
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq)]
+///Network statistics summary.
+pub struct Stats {
+    ///Connection tracking usage, if available.
+    pub conntrack: Option<Conntrack>,
+    ///Socket summary.
+    pub sockstat: SockStat,
+    ///Protocol counters.
+    pub snmp: Snmp,
+    ///Extended TCP counters.
+    pub netstat: Netstat,
+}
+
+///Reads network statistics.
+///
+///Returns `None` if `/proc/net` is not available.
+pub fn stats() -> Option<Stats> {
+    Some(Stats {
+        conntrack: Conntrack::new(),
+        sockstat: SockStat::new()?,
+        snmp: Snmp::new()?,
+        netstat: Netstat::new().unwrap_or_default(),
+    })
+}
@@ -0,0 +1,143 @@
+//! Network sysctl parameters, read from `/proc/sys/net`.
+
+use crate::unix::linux::fs;
+
+const IPV4_CONF: &[u8] = b"/proc/sys/net/ipv4/conf/";
+const IPV6_CONF: &[u8] = b"/proc/sys/net/ipv6/conf/";
+
+#[inline(always)]
+fn read_flag(parts: &[&[u8]]) -> Option<bool> {
+    fs::read_value::<u8>(parts).map(|value| value != 0)
+}
+
+///Returns whether IPv4 forwarding is enabled (`net.ipv4.ip_forward`).
+pub fn ip_forward() -> Option<bool> {
+    read_flag(&[b"/proc/sys/net/ipv4/ip_forward"])
+}
+
+///Returns whether IPv6 forwarding is enabled on all interfaces (`net.ipv6.conf.all.forwarding`).
+pub fn ipv6_forwarding() -> Option<bool> {
+    read_flag(&[IPV6_CONF, b"all/forwarding"])
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+///Range of ports used for outgoing connections (`net.ipv4.ip_local_port_range`).
+pub struct PortRange {
+    ///First port in the range.
+    pub start: u16,
+    ///Last port in the range, inclusive.
+    pub end: u16,
+}
+
+///Returns ephemeral port range.
+pub fn ip_local_port_range() -> Option<PortRange> {
+    let content = fs::read(&[b"/proc/sys/net/ipv4/ip_local_port_range"])?;
+    let content = core::str::from_utf8(&content).ok()?;
+    let mut parts = content.split_whitespace();
+    let start = parts.next()?.parse().ok()?;
+    let end = parts.next()?.parse().ok()?;
+
+    Some(PortRange {
+        start,
+        end
+    })
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+///Reverse path filtering mode (`rp_filter`).
+pub enum RpFilter {
+    ///No source validation.
+    Off,
+    ///Strict mode as defined in RFC 3704.
+    Strict,
+    ///Loose mode as defined in RFC 3704.
+    Loose,
+}
+
+impl RpFilter {
+    fn from_value(value: u8) -> Option<Self> {
+        match value {
+            0 => Some(RpFilter::Off),
+            1 => Some(RpFilter::Strict),
+            2 => Some(RpFilter::Loose),
+            _ => None,
+        }
+    }
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+///Router advertisements handling mode (`accept_ra`).
+pub enum AcceptRa {
+    ///Do not accept router advertisements.
+    Off,
+    ///Accept router advertisements only if forwarding is disabled.
+    On,
+    ///Accept router advertisements even if forwarding is enabled.
+    Always,
+}
+
+impl AcceptRa {
+    fn from_value(value: u8) -> Option<Self> {
+        match value {
+            0 => Some(AcceptRa::Off),
+            1 => Some(AcceptRa::On),
+            2 => Some(AcceptRa::Always),
+            _ => None,
+        }
+    }
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+///Interface's sysctl configuration.
+///
+///Each field is `None` when parameter is not available, for example IPv6 parameters on kernel
+///without IPv6 support.
+pub struct InterfaceConf {
+    ///IPv4 forwarding (`net.ipv4.conf.<if>.forwarding`).
+    pub ipv4_forwarding: Option<bool>,
+    ///IPv6 forwarding (`net.ipv6.conf.<if>.forwarding`).
+    pub ipv6_forwarding: Option<bool>,
+    ///Router advertisements handling (`net.ipv6.conf.<if>.accept_ra`).
+    pub accept_ra: Option<AcceptRa>,
+    ///Whether IPv6 is disabled (`net.ipv6.conf.<if>.disable_ipv6`).
+    pub disable_ipv6: Option<bool>,
+    ///Reverse path filtering (`net.ipv4.conf.<if>.rp_filter`).
+    pub rp_filter: Option<RpFilter>,
+    ///Proxy ARP (`net.ipv4.conf.<if>.proxy_arp`).
+    pub proxy_arp: Option<bool>,
+}
+
+impl InterfaceConf {
+    ///Reads configuration of interface by its name.
+    ///
+    ///Use `all` to get global configuration and `default` to get configuration applied to new interfaces.
+    ///
+    ///Returns `None` if interface has no configuration.
+    pub fn get(name: &str) -> Option<Self> {
+        let name = name.as_bytes();
+        //Prevent escaping configuration directory
+        if !fs::is_valid_name(name) {
+            return None;
+        }
+
+        let result = Self {
+            ipv4_forwarding: read_flag(&[IPV4_CONF, name, b"/forwarding"]),
+            ipv6_forwarding: read_flag(&[IPV6_CONF, name, b"/forwarding"]),
+            accept_ra: fs::read_value(&[IPV6_CONF, name, b"/accept_ra"]).and_then(AcceptRa::from_value),
+            disable_ipv6: read_flag(&[IPV6_CONF, name, b"/disable_ipv6"]),
+            rp_filter: fs::read_value(&[IPV4_CONF, name, b"/rp_filter"]).and_then(RpFilter::from_value),
+            proxy_arp: read_flag(&[IPV4_CONF, name, b"/proxy_arp"]),
+        };
+
+        match result {
+            Self { ipv4_forwarding: None, ipv6_forwarding: None, accept_ra: None, disable_ipv6: None, rp_filter: None, proxy_arp: None } => None,
+            result => Some(result),
+        }
+    }
+
+    #[inline(always)]
+    ///Reads global configuration, applied to all interfaces.
+    pub fn all() -> Option<Self> {
+        Self::get("all")
+    }
+}
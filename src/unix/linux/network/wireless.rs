@@ -0,0 +1,332 @@
+//! Wireless interface information.
+
+use core::{cmp, mem};
+
+use super::Interface;
+use crate::unix::linux::fs;
+
+const PHY_NAME_SIZE: usize = 32;
+const ESSID_MAX_SIZE: usize = 32;
+
+const SIOCGIWFREQ: libc::c_ulong = 0x8B05;
+const SIOCGIWMODE: libc::c_ulong = 0x8B07;
+const SIOCGIWESSID: libc::c_ulong = 0x8B1B;
+
+#[repr(C)]
+#[derive(Clone, Copy)]
+struct IwFreq {
+    m: i32,
+    e: i16,
+    i: u8,
+    flags: u8,
+}
+
+impl IwFreq {
+    ///Returns frequency in Hz, which is `m * 10^e`.
+    ///
+    ///Returns `None` if value is negative, overflows or denotes channel number.
+    fn hz(&self) -> Option<u64> {
+        //Small mantissa without exponent denotes channel
+        if self.m < 0 || (self.e == 0 && self.m < 1000) {
+            return None;
+        }
+
+        let mantissa = self.m as u64;
+        let scale = 10u64.checked_pow(self.e.unsigned_abs() as u32);
+        let result = match self.e >= 0 {
+            true => mantissa.checked_mul(scale?)?,
+            false => scale.map_or(0, |scale| mantissa / scale),
+        };
+
+        match result {
+            0 => None,
+            result => Some(result),
+        }
+    }
+}
+
+#[repr(C)]
+#[derive(Clone, Copy)]
+struct IwPoint {
+    pointer: *mut libc::c_void,
+    length: u16,
+    flags: u16,
+}
+
+#[repr(C)]
+#[derive(Clone, Copy)]
+union IwReqData {
+    mode: u32,
+    freq: IwFreq,
+    essid: IwPoint,
+    _addr: libc::sockaddr,
+}
+
+#[repr(C)]
+struct IwReq {
+    ifr_name: [u8; libc::IFNAMSIZ],
+    u: IwReqData,
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+///Operating mode of wireless interface.
+pub enum WirelessMode {
+    ///Mode is chosen by driver.
+    Auto,
+    ///Ad-hoc network (IBSS).
+    AdHoc,
+    ///Station, connected to access point.
+    Managed,
+    ///Access point.
+    Master,
+    ///Wireless repeater.
+    Repeater,
+    ///Secondary master or repeater.
+    Secondary,
+    ///Passive monitor.
+    Monitor,
+    ///Mesh point.
+    Mesh,
+    ///Mode, unknown to this library.
+    Unknown(u32),
+}
+
+impl WirelessMode {
+    const fn from_value(value: u32) -> Self {
+        match value {
+            0 => WirelessMode::Auto,
+            1 => WirelessMode::AdHoc,
+            2 => WirelessMode::Managed,
+            3 => WirelessMode::Master,
+            4 => WirelessMode::Repeater,
+            5 => WirelessMode::Secondary,
+            6 => WirelessMode::Monitor,
+            7 => WirelessMode::Mesh,
+            value => WirelessMode::Unknown(value),
+        }
+    }
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+///Link quality, as reported by `/proc/net/wireless`.
+pub struct LinkQuality {
+    ///Device dependent status.
+    pub status: u16,
+    ///Overall link quality.
+    pub link: i32,
+    ///Signal level, commonly in dBm.
+    pub level: i32,
+    ///Noise level, commonly in dBm.
+    pub noise: i32,
+}
+
+impl LinkQuality {
+    ///Parses content of `/proc/net/wireless`, looking for interface with specified `name`.
+    pub fn parse(content: &[u8], name: &str) -> Option<Self> {
+        fn parse_value(value: &str) -> Option<i32> {
+            //Values are suffixed with `.` when updated since last read
+            value.trim_end_matches('.').parse().ok()
+        }
+
+        let content = core::str::from_utf8(content).ok()?;
+        for line in content.lines() {
+            let (interface, stats) = match line.split_once(':') {
+                Some(line) => line,
+                None => continue,
+            };
+
+            if interface.trim() != name {
+                continue;
+            }
+
+            let mut stats = stats.split_whitespace();
+            return Some(Self {
+                status: u16::from_str_radix(stats.next()?, 16).ok()?,
+                link: parse_value(stats.next()?)?,
+                level: parse_value(stats.next()?)?,
+                noise: parse_value(stats.next()?)?,
+            });
+        }
+
+        None
+    }
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+///Wireless interface information.
+pub struct WirelessInfo {
+    phy: [u8; PHY_NAME_SIZE],
+    ssid: [u8; ESSID_MAX_SIZE],
+    ssid_len: u8,
+    ///Operating mode, if available.
+    pub mode: Option<WirelessMode>,
+    ///Current frequency in Hz, if available.
+    pub frequency: Option<u64>,
+    ///Link quality, if interface is associated.
+    pub link: Option<LinkQuality>,
+}
+
+impl WirelessInfo {
+    ///Reads information available from `sysfs` and `procfs` files, mounted at `root`.
+    ///
+    ///Mode, frequency and SSID are not available from files and are always `None`.
+    ///
+    ///Returns `None` if interface is not wireless.
+    pub fn with_root(root: &str, name: &str) -> Option<Self> {
+        if !is_wireless_with_root(root, name) {
+            return None;
+        }
+
+        let root = root.as_bytes();
+        let mut phy = [0u8; PHY_NAME_SIZE];
+        if let Some(name) = fs::read(&[root, b"/sys/class/net/", name.as_bytes(), b"/phy80211/name"]) {
+            let name = name.split(|byte| *byte == b'\n').next().unwrap_or(&[]);
+            let len = cmp::min(phy.len(), name.len());
+            phy[..len].copy_from_slice(&name[..len]);
+        }
+
+        let link = fs::read(&[root, b"/proc/net/wireless"]).and_then(|content| LinkQuality::parse(&content, name));
+
+        Some(Self {
+            phy,
+            ssid: [0; ESSID_MAX_SIZE],
+            ssid_len: 0,
+            mode: None,
+            frequency: None,
+            link,
+        })
+    }
+
+    ///Retrieves information about wireless interface.
+    ///
+    ///Mode, frequency and SSID are queried using wireless extensions, when supported by kernel.
+    ///
+    ///Returns `None` if interface is not wireless.
+    pub fn get(name: &str) -> Option<Self> {
+        let mut result = Self::with_root("", name)?;
+        if name.len() >= libc::IFNAMSIZ {
+            return Some(result);
+        }
+
+        let fd = unsafe {
+            libc::socket(libc::AF_INET, libc::SOCK_DGRAM | libc::SOCK_CLOEXEC, 0)
+        };
+        if fd == -1 {
+            return Some(result);
+        }
+
+        let mut req = IwReq {
+            ifr_name: [0; libc::IFNAMSIZ],
+            u: unsafe {
+                mem::MaybeUninit::<IwReqData>::zeroed().assume_init()
+            },
+        };
+        req.ifr_name[..name.len()].copy_from_slice(name.as_bytes());
+
+        unsafe {
+            if libc::ioctl(fd, SIOCGIWMODE as _, &mut req as *mut IwReq) == 0 {
+                result.mode = Some(WirelessMode::from_value(req.u.mode));
+            }
+
+            if libc::ioctl(fd, SIOCGIWFREQ as _, &mut req as *mut IwReq) == 0 {
+                result.frequency = req.u.freq.hz();
+            }
+
+            req.u.essid = IwPoint {
+                pointer: result.ssid.as_mut_ptr() as _,
+                length: ESSID_MAX_SIZE as u16,
+                flags: 0,
+            };
+            if libc::ioctl(fd, SIOCGIWESSID as _, &mut req as *mut IwReq) == 0 {
+                result.ssid_len = cmp::min(req.u.essid.length as usize, ESSID_MAX_SIZE) as u8;
+            }
+
+            libc::close(fd);
+        }
+
+        Some(result)
+    }
+
+    #[inline]
+    ///Returns name of the wireless PHY (e.g. `phy0`), if available as utf-8 string.
+    pub fn phy(&self) -> Option<&str> {
+        let len = self.phy.iter().position(|byte| *byte == 0).unwrap_or(self.phy.len());
+        match len {
+            0 => None,
+            len => core::str::from_utf8(&self.phy[..len]).ok(),
+        }
+    }
+
+    #[inline]
+    ///Returns SSID of the network, interface is associated with.
+    pub fn ssid(&self) -> Option<&[u8]> {
+        match self.ssid_len {
+            0 => None,
+            len => Some(&self.ssid[..len as usize]),
+        }
+    }
+}
+
+///Returns whether interface is wireless, checking `sysfs` mounted at `root`.
+pub(crate) fn is_wireless_with_root(root: &str, name: &str) -> bool {
+    let root = root.as_bytes();
+    let name = name.as_bytes();
+
+    fs::is_valid_name(name) && (
+        fs::exists(&[root, b"/sys/class/net/", name, b"/wireless"]) ||
+        fs::exists(&[root, b"/sys/class/net/", name, b"/phy80211"])
+    )
+}
+
+impl<'a> Interface<'a> {
+    #[inline]
+    ///Returns whether interface is wireless.
+    pub fn is_wireless(&self) -> bool {
+        match core::str::from_utf8(self.data.name()) {
+            Ok(name) => is_wireless_with_root("", name),
+            Err(_) => false,
+        }
+    }
+
+    #[inline]
+    ///Returns wireless information, if interface is wireless.
+    pub fn wireless_info(&self) -> Option<WirelessInfo> {
+        let name = core::str::from_utf8(self.data.name()).ok()?;
+        WirelessInfo::get(name)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::IwFreq;
+
+    fn hz(m: i32, e: i16) -> Option<u64> {
+        IwFreq {
+            m,
+            e,
+            i: 0,
+            flags: 0,
+        }.hz()
+    }
+
+    #[test]
+    fn should_convert_frequency() {
+        assert_eq!(hz(2412, 6), Some(2_412_000_000));
+        assert_eq!(hz(241_200_000, 1), Some(2_412_000_000));
+        assert_eq!(hz(2_000_000_000, 0), Some(2_000_000_000));
+        assert_eq!(hz(i32::MAX, 9), Some(2_147_483_647_000_000_000));
+        assert_eq!(hz(i32::MAX, 10), None);
+        assert_eq!(hz(1, 20), None);
+
+        assert_eq!(hz(2_147_483_000, -1), Some(214_748_300));
+        assert_eq!(hz(5_180_000, -3), Some(5_180));
+        assert_eq!(hz(1000, -4), None);
+        assert_eq!(hz(i32::MAX, i16::MIN), None);
+
+        //Channel
+        assert_eq!(hz(6, 0), None);
+        assert_eq!(hz(999, 0), None);
+        assert_eq!(hz(-2412, 6), None);
+        assert_eq!(hz(-1, 0), None);
+    }
+}
@@ -0,0 +1,105 @@
+//! NUMA topology.
+
+extern crate alloc;
+
+use alloc::vec::Vec;
+
+pub use crate::data::numa::{Node, LOCAL_DISTANCE};
+use crate::data::cpu::CpuSet;
+use crate::unix::linux::fs;
+use crate::unix::linux::cpu;
+
+const NODE_DIR: &[u8] = b"/sys/devices/system/node/";
+const CPU_ONLINE: &[u8] = b"/sys/devices/system/cpu/online";
+
+fn read_list(parts: &[&[u8]]) -> Option<CpuSet> {
+    let content = fs::read(parts)?;
+    CpuSet::parse(core::str::from_utf8(&content).ok()?)
+}
+
+///Parses `MemTotal` and `MemFree` out of node's `meminfo`, returning them in bytes.
+fn parse_meminfo(content: &[u8]) -> (u64, u64) {
+    let mut total = 0;
+    let mut free = 0;
+    let content = match core::str::from_utf8(content) {
+        Ok(content) => content,
+        Err(_) => return (total, free),
+    };
+
+    //Format: Node <id> <name>: <value> kB
+    for line in content.lines() {
+        let mut parts = line.split_whitespace().skip(2);
+        match (parts.next(), parts.next().and_then(|value| value.parse::<u64>().ok())) {
+            (Some("MemTotal:"), Some(value)) => total = value.saturating_mul(1024),
+            (Some("MemFree:"), Some(value)) => free = value.saturating_mul(1024),
+            _ => (),
+        }
+    }
+
+    (total, free)
+}
+
+///Reads NUMA nodes from `sysfs`, mounted at `root`.
+///
+///If NUMA is not supported by kernel, single node is returned, covering all online CPUs and system memory.
+///Unlike other platforms, which cover `0..cpu::count()`, CPUs are read from `/sys/devices/system/cpu/online`,
+///as `cpu::count()` is limited by affinity and doesn't denote CPU indexes.
+///It falls back to `0..cpu::count()` only if list of online CPUs is not available.
+pub fn nodes_with_root(root: &str) -> Vec<Node> {
+    let root = root.as_bytes();
+    let mut result = Vec::new();
+
+    let online = match read_list(&[root, NODE_DIR, b"online"]) {
+        Some(online) if !online.is_empty() => online,
+        _ => {
+            let cpus = match read_list(&[root, CPU_ONLINE]) {
+                Some(cpus) if !cpus.is_empty() => cpus,
+                _ => (0..cpu::count()).collect(),
+            };
+            result.push(Node::single(cpus));
+            return result;
+        }
+    };
+
+    let mut buffer = [0u8; fs::NUMBER_SIZE];
+    for id in online.iter() {
+        let node = fs::format_number(id, &mut buffer);
+        let (total_memory, free_memory) = match fs::read(&[root, NODE_DIR, b"node", node, b"/meminfo"]) {
+            Some(meminfo) => parse_meminfo(&meminfo),
+            None => (0, 0),
+        };
+
+        //Distances are listed in order of online nodes
+        let distances = match fs::read(&[root, NODE_DIR, b"node", node, b"/distance"]) {
+            Some(distance) => match core::str::from_utf8(&distance) {
+                Ok(distance) => online.iter().zip(distance.split_whitespace()).filter_map(|(id, distance)| Some((id, distance.parse().ok()?))).collect(),
+                Err(_) => Vec::new(),
+            },
+            None => Vec::new(),
+        };
+
+        result.push(Node {
+            id,
+            //Memory-only nodes have no CPUs
+            cpus: read_list(&[root, NODE_DIR, b"node", node, b"/cpulist"]).unwrap_or_default(),
+            total_memory,
+            free_memory,
+            distances,
+        });
+    }
+
+    result
+}
+
+#[inline]
+///Returns online NUMA nodes of the system, ordered by id.
+///
+///Uses `/sys/devices/system/node/node*/{cpulist,meminfo,distance}`.
+///
+///If NUMA is not supported by kernel, single node is returned, covering all online CPUs and system memory.
+///Unlike other platforms, which cover `0..cpu::count()`, CPUs are read from `/sys/devices/system/cpu/online`,
+///as `cpu::count()` is limited by affinity and doesn't denote CPU indexes.
+///It falls back to `0..cpu::count()` only if list of online CPUs is not available.
+pub fn nodes() -> Vec<Node> {
+    nodes_with_root("")
+}
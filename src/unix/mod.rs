@@ -14,6 +14,8 @@ pub use linux::*;
 
 pub use crate::data::host::HostName;
 
+use core::ptr;
+
 impl HostName {
     ///Retrieves host's name.
     pub fn get() -> Option<HostName> {
@@ -29,3 +31,66 @@ impl HostName {
         }
     }
 }
+
+impl HostName {
+    ///Retrieves host's fully qualified domain name.
+    ///
+    ///Resolves canonical name of `HostName::get` using `getaddrinfo`, which commonly consults `/etc/hosts` before DNS.
+    ///
+    ///Returns `None` if name cannot be resolved.
+    pub fn fqdn() -> Option<HostName> {
+        let name = Self::get()?;
+        let mut c_name = [0u8; HostName::capacity() + 1];
+        c_name[..name.as_bytes().len()].copy_from_slice(name.as_bytes());
+
+        let mut hints = unsafe {
+            core::mem::MaybeUninit::<libc::addrinfo>::zeroed().assume_init()
+        };
+        hints.ai_family = libc::AF_UNSPEC;
+        hints.ai_flags = libc::AI_CANONNAME;
+
+        let mut info = ptr::null_mut();
+        unsafe {
+            if libc::getaddrinfo(c_name.as_ptr() as _, ptr::null(), &hints, &mut info) != 0 {
+                return None;
+            }
+
+            let canonname = match info.as_ref() {
+                Some(info) if !info.ai_canonname.is_null() => info.ai_canonname,
+                _ => {
+                    libc::freeaddrinfo(info);
+                    return None;
+                }
+            };
+
+            let result = HostName::from_slice(core::slice::from_raw_parts(canonname as *const u8, libc::strlen(canonname)));
+            libc::freeaddrinfo(info);
+            Some(result)
+        }
+    }
+
+    ///Sets host's name.
+    ///
+    ///Requires `CAP_SYS_ADMIN` or equivalent privilege.
+    ///Use `HostName::parse` to make sure name is valid before setting it.
+    ///
+    ///Returns `true` on success, otherwise please check `std::io::Error::last_os_error()`
+    pub fn set(name: &HostName) -> bool {
+        let name = name.as_bytes();
+        unsafe {
+            libc::sethostname(name.as_ptr() as _, name.len() as _) == 0
+        }
+    }
+
+    ///Retrieves NIS/YP domain name, as reported by `uname`.
+    ///
+    ///Returns `None` if it is not set or not supported by system.
+    pub fn nis_domain() -> Option<HostName> {
+        let uname = host::Uname::get()?;
+        match uname.domainname() {
+            Ok("") => None,
+            Ok(domain) => Some(HostName::from_slice(domain.as_bytes())),
+            Err(domain) => Some(HostName::from_slice(domain)),
+        }
+    }
+}
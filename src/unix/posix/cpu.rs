@@ -1,5 +1,20 @@
 //! CPU information.
 
+#[cfg(not(any(target_os = "linux", target_os = "android")))]
+pub use crate::data::cpu::{CpuSet, CpuSetIter};
+#[cfg(not(any(target_os = "linux", target_os = "android")))]
+pub use crate::data::cpu_info::{info, has_feature, Info, Feature};
+
+#[inline]
+fn sysconf_count(name: libc::c_int) -> usize {
+    let cpus = unsafe { libc::sysconf(name) };
+    if cpus < 1 {
+        1
+    } else {
+        cpus as usize
+    }
+}
+
 ///Returns number of CPU cores on system, as reported by OS.
 pub fn count() -> usize {
     #[cfg(any(target_arch = "arm", target_arch = "aarch64"))]
@@ -7,10 +22,73 @@ pub fn count() -> usize {
     #[cfg(not(any(target_arch = "arm", target_arch = "aarch64")))]
     const CONF_NAME: libc::c_int = libc::_SC_NPROCESSORS_ONLN;
 
-    let cpus = unsafe { libc::sysconf(CONF_NAME) };
-    if cpus < 1 {
-        1
-    } else {
-        cpus as usize
-    }
+    sysconf_count(CONF_NAME)
+}
+
+///Returns number of CPUs, configured by OS (`_SC_NPROCESSORS_CONF`).
+///
+///It may include CPUs, that are offline or not even present.
+pub fn configured_count() -> usize {
+    sysconf_count(libc::_SC_NPROCESSORS_CONF)
+}
+
+///Returns number of CPUs, currently online (`_SC_NPROCESSORS_ONLN`).
+pub fn online_count() -> usize {
+    sysconf_count(libc::_SC_NPROCESSORS_ONLN)
+}
+
+#[cfg(not(any(target_os = "linux", target_os = "android", target_os = "macos", target_os = "ios")))]
+///Returns number of logical CPUs (hardware threads) on system.
+///
+///Topology is not available on this platform, hence it is the same as `configured_count()`.
+pub fn logical_count() -> usize {
+    configured_count()
+}
+
+#[cfg(not(any(target_os = "linux", target_os = "android", target_os = "macos", target_os = "ios")))]
+///Returns number of CPUs, current process is allowed to run on.
+///
+///Affinity is not available on this platform, hence it is the same as `online_count()`.
+pub fn available_count() -> usize {
+    online_count()
+}
+
+#[cfg(not(any(target_os = "linux", target_os = "android", target_os = "macos", target_os = "ios")))]
+///Returns number of physical CPU cores on system.
+///
+///Topology is not available on this platform, hence it is the same as `logical_count()`.
+pub fn physical_core_count() -> usize {
+    logical_count()
+}
+
+#[cfg(not(any(target_os = "linux", target_os = "android", target_os = "macos", target_os = "ios")))]
+///Returns number of CPU sockets (physical packages) on system.
+///
+///Topology is not available on this platform, hence it is always 1.
+pub fn socket_count() -> usize {
+    1
+}
+
+#[cfg(not(any(target_os = "linux", target_os = "android")))]
+///Retrieves set of CPUs, thread or process with `pid` is allowed to run on.
+///
+///Affinity is not available on this platform, hence it is always `None`.
+pub fn affinity(_pid: u32) -> Option<CpuSet> {
+    None
+}
+
+#[cfg(not(any(target_os = "linux", target_os = "android")))]
+///Sets CPUs, thread or process with `pid` is allowed to run on.
+///
+///Affinity is not available on this platform, hence it always fails.
+pub fn set_affinity(_pid: u32, _cpus: &CpuSet) -> bool {
+    false
+}
+
+#[cfg(not(any(target_os = "linux", target_os = "android")))]
+///Returns number of CPUs, current process can effectively utilize.
+///
+///Neither affinity nor CPU quotas are supported on this platform, hence it is the same as `online_count()`.
+pub fn effective_parallelism() -> usize {
+    online_count()
 }
@@ -0,0 +1,76 @@
+//! Host information.
+
+use core::mem;
+
+pub use crate::data::host::{HostName, HostNameError, Uname, OsRelease, LoadAverage, Id128};
+
+impl Uname {
+    ///Retrieves kernel and operating system identity.
+    ///
+    ///In case of failure please check `std::io::Error::last_os_error()`
+    pub fn get() -> Option<Self> {
+        let mut raw = mem::MaybeUninit::<libc::utsname>::zeroed();
+        let raw = unsafe {
+            if libc::uname(raw.as_mut_ptr()) != 0 {
+                return None;
+            }
+            raw.assume_init()
+        };
+
+        let mut result = Self::new();
+        Self::copy_field(&mut result.sysname, &raw.sysname);
+        Self::copy_field(&mut result.release, &raw.release);
+        Self::copy_field(&mut result.version, &raw.version);
+        Self::copy_field(&mut result.machine, &raw.machine);
+        #[cfg(any(target_os = "linux", target_os = "android"))]
+        Self::copy_field(&mut result.domainname, &raw.domainname);
+        //Linux reports unset domain as `(none)`
+        if result.domainname() == Ok("(none)") {
+            result.domainname.fill(0);
+        }
+
+        Some(result)
+    }
+}
+
+#[cfg(not(any(target_os = "linux", target_os = "android", target_os = "macos", target_os = "ios")))]
+#[inline(always)]
+///Returns time elapsed since boot.
+///
+///Not available on this platform.
+pub fn uptime() -> Option<core::time::Duration> {
+    None
+}
+
+#[cfg(not(any(target_os = "linux", target_os = "android", target_os = "macos", target_os = "ios")))]
+#[inline(always)]
+///Returns boot time as duration since Unix epoch.
+///
+///Not available on this platform.
+pub fn boot_time() -> Option<core::time::Duration> {
+    None
+}
+
+#[cfg(not(target_os = "android"))]
+///Returns system load averages, using `getloadavg`.
+///
+///Task counts are not available.
+pub fn load_average() -> Option<LoadAverage> {
+    let mut loads = [0f64; 3];
+    let res = unsafe {
+        libc::getloadavg(loads.as_mut_ptr(), loads.len() as _)
+    };
+
+    if res == loads.len() as _ {
+        Some(LoadAverage {
+            one: loads[0],
+            five: loads[1],
+            fifteen: loads[2],
+            running: None,
+            total: None,
+            last_pid: None,
+        })
+    } else {
+        None
+    }
+}
@@ -1,4 +1,7 @@
 pub mod cpu;
 pub mod network;
+pub mod host;
+#[cfg(not(any(target_os = "linux", target_os = "android")))]
+pub mod numa;
 #[cfg(not(any(target_os = "macos", target_os = "ios")))]
 pub mod mem;
@@ -5,9 +5,13 @@ extern crate alloc;
 use alloc::vec::Vec;
 use alloc::borrow::Cow;
 
-use core::{slice, iter};
+use core::{slice, iter, cmp};
 
 pub use crate::data::network::{Ip, Address};
+use crate::data::network::diff_addresses;
+
+///Change between two [Interfaces](struct.Interfaces.html) snapshots.
+pub type Change<'a> = crate::data::network::Change<Interface<'a>>;
 
 #[inline(always)]
 pub(crate) fn slice_c_str(input: &[u8; libc::IFNAMSIZ]) -> &[u8] {
@@ -21,6 +25,7 @@ pub(crate) fn slice_c_str(input: &[u8; libc::IFNAMSIZ]) -> &[u8] {
 }
 
 ///Iterator over socket addresses
+#[derive(Clone)]
 pub struct Addresses<'a> {
     cursor: iter::Copied<slice::Iter<'a, Address>>
 }
@@ -59,8 +64,9 @@ impl InterfaceData {
 }
 
 ///Network interface
+#[derive(Clone, Copy)]
 pub struct Interface<'a> {
-    data: &'a InterfaceData
+    pub(crate) data: &'a InterfaceData
 }
 
 impl<'a> Interface<'a> {
@@ -81,6 +87,18 @@ impl<'a> Interface<'a> {
             cursor: self.data.addresses.iter().copied()
         }
     }
+
+    #[inline]
+    ///Returns index of the interface, if it still exists.
+    pub fn index(&self) -> Option<u32> {
+        let mut name = self.data.name;
+        //Make sure name is null terminated
+        name[libc::IFNAMSIZ - 1] = 0;
+        match unsafe { libc::if_nametoindex(name.as_ptr() as _) } {
+            0 => None,
+            index => Some(index),
+        }
+    }
 }
 
 ///Iterator over [Interfaces](struct.Interfaces.html)
@@ -128,9 +146,40 @@ impl Interfaces {
         }
     }
 
+    ///Computes list of changes required to get from `old` to `new` snapshot.
+    ///
+    ///Interfaces are matched by name, while addresses are matched by IP.
+    pub fn diff<'a>(old: &'a Interfaces, new: &'a Interfaces) -> Vec<Change<'a>> {
+        let mut result = Vec::new();
+        let mut old = old.inner.iter().map(|data| Interface { data }).peekable();
+        let mut new = new.inner.iter().map(|data| Interface { data }).peekable();
+
+        //Both snapshots are sorted by name
+        loop {
+            let ordering = match (old.peek(), new.peek()) {
+                (Some(old), Some(new)) => old.data.name().cmp(new.data.name()),
+                (Some(_), None) => cmp::Ordering::Less,
+                (None, Some(_)) => cmp::Ordering::Greater,
+                (None, None) => break result,
+            };
+
+            match ordering {
+                cmp::Ordering::Less => if let Some(old) = old.next() {
+                    result.push(Change::InterfaceRemoved(old));
+                },
+                cmp::Ordering::Greater => if let Some(new) = new.next() {
+                    result.push(Change::InterfaceAdded(new));
+                },
+                cmp::Ordering::Equal => if let (Some(old), Some(new)) = (old.next(), new.next()) {
+                    diff_addresses(old, old.addresses(), new, new.addresses(), &mut result);
+                },
+            }
+        }
+    }
+
     #[cfg(not(any(target_os = "linux", target_os = "android")))]
     fn store_interface(&mut self, ifa_name: *const i8) -> &mut InterfaceData {
-        use core::{cmp, ptr};
+        use core::ptr;
 
         let mut name = [0u8; libc::IFNAMSIZ];
         if !ifa_name.is_null() {
@@ -297,3 +346,61 @@ impl<'a> IntoIterator for &'a Interfaces {
         self.iter()
     }
 }
+
+#[cfg(test)]
+mod tests {
+    extern crate alloc;
+
+    use alloc::vec::Vec;
+
+    use super::{Address, Change, Interfaces, InterfaceData, Ip};
+
+    fn interface(name: &str, addresses: &[Address]) -> InterfaceData {
+        let mut data = InterfaceData {
+            name: [0u8; libc::IFNAMSIZ],
+            addresses: addresses.to_vec(),
+        };
+        data.name[..name.len()].copy_from_slice(name.as_bytes());
+        data
+    }
+
+    #[test]
+    fn should_diff_interfaces() {
+        let addr = Address {
+            ip: Ip::V4([10, 0, 0, 1]),
+            prefix: 24,
+        };
+        let new_addr = Address {
+            ip: Ip::V4([10, 0, 0, 2]),
+            prefix: 24,
+        };
+
+        //Snapshots are sorted by name
+        let old = Interfaces {
+            inner: Vec::from([interface("eth0", &[addr]), interface("lo", &[])]),
+        };
+        let new = Interfaces {
+            inner: Vec::from([interface("eth0", &[addr, new_addr]), interface("wlan0", &[])]),
+        };
+
+        let changes = Interfaces::diff(&old, &new);
+        assert_eq!(changes.len(), 3);
+        match changes[0] {
+            Change::AddressAdded(interface, address) => {
+                assert_eq!(interface.data.name(), b"eth0");
+                assert_eq!(address, new_addr);
+            },
+            _ => panic!("Unexpected change"),
+        }
+        match changes[1] {
+            Change::InterfaceRemoved(interface) => assert_eq!(interface.data.name(), b"lo"),
+            _ => panic!("Unexpected change"),
+        }
+        match changes[2] {
+            Change::InterfaceAdded(interface) => assert_eq!(interface.data.name(), b"wlan0"),
+            _ => panic!("Unexpected change"),
+        }
+
+        assert!(Interfaces::diff(&new, &new).is_empty());
+    }
+}
@@ -1,6 +1,58 @@
 //! CPU information.
 
+pub use crate::data::cpu::{CpuSet, CpuSetIter};
+pub use crate::data::cpu_info::{info, has_feature, Info, Feature};
+
 ///Returns number of CPU cores on system, as reported by OS.
 pub fn count() -> usize {
     1
 }
+
+///Returns number of logical CPUs (hardware threads) on system.
+pub fn logical_count() -> usize {
+    1
+}
+
+///Returns number of physical CPU cores on system.
+pub fn physical_core_count() -> usize {
+    1
+}
+
+///Returns number of CPU sockets (physical packages) on system.
+pub fn socket_count() -> usize {
+    1
+}
+
+///Returns number of CPUs, currently online.
+pub fn online_count() -> usize {
+    1
+}
+
+///Returns number of CPUs, configured by OS.
+pub fn configured_count() -> usize {
+    1
+}
+
+///Returns number of CPUs, current process is allowed to run on.
+pub fn available_count() -> usize {
+    1
+}
+
+///Returns number of CPUs, current process can effectively utilize.
+pub fn effective_parallelism() -> usize {
+    available_count()
+}
+
+///Retrieves set of CPUs, thread or process with `pid` is allowed to run on.
+///
+///Affinity is not available on this platform, hence it is always `None`.
+pub fn affinity(_pid: u32) -> Option<CpuSet> {
+    None
+}
+
+///Sets CPUs, thread or process with `pid` is allowed to run on.
+///
+///Affinity is not available on this platform, hence it always fails.
+pub fn set_affinity(_pid: u32, _cpus: &CpuSet) -> bool {
+    false
+}
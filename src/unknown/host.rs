@@ -0,0 +1,31 @@
+//! Host information.
+
+pub use crate::data::host::{HostName, HostNameError, Uname, OsRelease, LoadAverage, Id128};
+
+impl Uname {
+    #[inline(always)]
+    ///Retrieves kernel and operating system identity.
+    pub fn get() -> Option<Self> {
+        Some(Self::new())
+    }
+}
+
+#[inline(always)]
+///Returns time elapsed since boot.
+pub fn uptime() -> Option<core::time::Duration> {
+    None
+}
+
+#[inline(always)]
+///Returns boot time as duration since Unix epoch.
+pub fn boot_time() -> Option<core::time::Duration> {
+    None
+}
+
+#[inline(always)]
+///Returns system load averages.
+///
+///Not available on this platform.
+pub fn load_average() -> Option<LoadAverage> {
+    None
+}
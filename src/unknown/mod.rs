@@ -1,6 +1,8 @@
 pub mod cpu;
 pub mod network;
 pub mod mem;
+pub mod host;
+pub mod numa;
 
 pub use crate::data::host::HostName;
 
@@ -9,4 +11,24 @@ impl HostName {
     pub fn get() -> Option<HostName> {
         Some(HostName::new())
     }
+
+    #[inline(always)]
+    ///Retrieves host's fully qualified domain name.
+    pub fn fqdn() -> Option<HostName> {
+        Some(HostName::new())
+    }
+
+    #[inline(always)]
+    ///Sets host's name.
+    ///
+    ///Not supported on this platform.
+    pub fn set(_name: &HostName) -> bool {
+        false
+    }
+
+    #[inline(always)]
+    ///Retrieves NIS/YP domain name.
+    pub fn nis_domain() -> Option<HostName> {
+        None
+    }
 }
@@ -5,8 +5,13 @@ pub use crate::data::network::{Ip, Address};
 extern crate alloc;
 
 use alloc::borrow::Cow;
+use alloc::vec::Vec;
+
+///Change between two [Interfaces](struct.Interfaces.html) snapshots.
+pub type Change<'a> = crate::data::network::Change<Interface<'a>>;
 
 ///Iterator over socket addresses
+#[derive(Clone)]
 pub struct Addresses<'a> {
     _inner: &'a Interfaces,
 }
@@ -28,6 +33,7 @@ impl<'a> Iterator for Addresses<'a> {
 }
 
 ///Interface
+#[derive(Clone, Copy)]
 pub struct Interface<'a> {
     _inner: &'a Interfaces,
 }
@@ -95,6 +101,12 @@ impl Interfaces {
             _inner: self
         }
     }
+
+    #[inline(always)]
+    ///Computes list of changes required to get from `old` to `new` snapshot.
+    pub fn diff<'a>(_old: &'a Interfaces, _new: &'a Interfaces) -> Vec<Change<'a>> {
+        Vec::new()
+    }
 }
 
 impl<'a> IntoIterator for &'a Interfaces {
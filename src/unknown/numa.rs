@@ -0,0 +1,3 @@
+//! NUMA topology.
+
+pub use crate::data::numa::{nodes, Node, LOCAL_DISTANCE};
@@ -1,10 +1,18 @@
 //! CPU information.
 
-use core::mem;
+extern crate alloc;
+
+use alloc::vec::Vec;
+
+use core::{mem, ptr};
+
+pub use crate::data::cpu::{CpuSet, CpuSetIter};
+pub use crate::data::cpu_info::{info, has_feature, Info, Feature};
 
 use windows_sys::Win32::System::SystemInformation::{SYSTEM_INFO, GetSystemInfo};
+use windows_sys::Win32::System::SystemInformation::{SYSTEM_LOGICAL_PROCESSOR_INFORMATION, GetLogicalProcessorInformation, RelationProcessorCore, RelationProcessorPackage};
 
-fn system_info() -> SYSTEM_INFO {
+pub(crate) fn system_info() -> SYSTEM_INFO {
     let mut info = mem::MaybeUninit::<SYSTEM_INFO>::uninit();
     unsafe {
         GetSystemInfo(info.as_mut_ptr());
@@ -13,7 +21,101 @@ fn system_info() -> SYSTEM_INFO {
 
 }
 
+fn processor_info() -> Option<Vec<SYSTEM_LOGICAL_PROCESSOR_INFORMATION>> {
+    let mut len = 0u32;
+    unsafe {
+        GetLogicalProcessorInformation(ptr::null_mut(), &mut len);
+    }
+
+    let capacity = len as usize / mem::size_of::<SYSTEM_LOGICAL_PROCESSOR_INFORMATION>();
+    if capacity == 0 {
+        return None;
+    }
+
+    let mut result = Vec::with_capacity(capacity);
+    unsafe {
+        if GetLogicalProcessorInformation(result.as_mut_ptr(), &mut len) == 0 {
+            return None;
+        }
+        result.set_len(len as usize / mem::size_of::<SYSTEM_LOGICAL_PROCESSOR_INFORMATION>());
+    }
+
+    Some(result)
+}
+
 ///Returns number of CPU cores on system, as reported by OS.
 pub fn count() -> usize {
     system_info().dwNumberOfProcessors as usize
 }
+
+///Returns number of logical CPUs (hardware threads) on system.
+///
+///Limited to processor group of the current process.
+pub fn logical_count() -> usize {
+    match processor_info() {
+        Some(info) => info.iter()
+                          .filter(|info| info.Relationship == RelationProcessorCore)
+                          .map(|info| info.ProcessorMask.count_ones() as usize)
+                          .sum(),
+        None => count(),
+    }
+}
+
+///Returns number of physical CPU cores on system.
+///
+///Limited to processor group of the current process.
+pub fn physical_core_count() -> usize {
+    match processor_info() {
+        Some(info) => info.iter().filter(|info| info.Relationship == RelationProcessorCore).count(),
+        None => count(),
+    }
+}
+
+///Returns number of CPU sockets (physical packages) on system.
+pub fn socket_count() -> usize {
+    match processor_info() {
+        Some(info) => match info.iter().filter(|info| info.Relationship == RelationProcessorPackage).count() {
+            0 => 1,
+            count => count,
+        },
+        None => 1,
+    }
+}
+
+#[inline(always)]
+///Returns number of CPUs, currently online.
+pub fn online_count() -> usize {
+    count()
+}
+
+#[inline(always)]
+///Returns number of CPUs, configured by OS.
+pub fn configured_count() -> usize {
+    logical_count()
+}
+
+#[inline(always)]
+///Returns number of CPUs, current process is allowed to run on.
+pub fn available_count() -> usize {
+    count()
+}
+
+#[inline(always)]
+///Returns number of CPUs, current process can effectively utilize.
+pub fn effective_parallelism() -> usize {
+    available_count()
+}
+
+///Retrieves set of CPUs, thread or process with `pid` is allowed to run on.
+///
+///Affinity is not available on this platform, hence it is always `None`.
+pub fn affinity(_pid: u32) -> Option<CpuSet> {
+    None
+}
+
+///Sets CPUs, thread or process with `pid` is allowed to run on.
+///
+///Affinity is not available on this platform, hence it always fails.
+pub fn set_affinity(_pid: u32, _cpus: &CpuSet) -> bool {
+    false
+}
@@ -0,0 +1,61 @@
+//! Host information.
+
+pub use crate::data::host::{HostName, HostNameError, Uname, OsRelease, LoadAverage, Id128};
+
+use core::{mem, time::Duration};
+
+use windows_sys::Win32::Foundation::FILETIME;
+use windows_sys::Win32::System::SystemInformation::{GetTickCount64, GetSystemTimeAsFileTime};
+
+//Difference between 1601-01-01 and 1970-01-01 in seconds
+const UNIX_EPOCH_OFFSET: u64 = 11_644_473_600;
+
+impl Uname {
+    ///Retrieves kernel and operating system identity.
+    ///
+    ///Only `sysname` and `machine` are available.
+    pub fn get() -> Option<Self> {
+        let info = crate::win32::cpu::system_info();
+        let machine: &[u8] = match unsafe { info.Anonymous.Anonymous.wProcessorArchitecture } {
+            0 => b"x86", //PROCESSOR_ARCHITECTURE_INTEL
+            5 => b"arm", //PROCESSOR_ARCHITECTURE_ARM
+            6 => b"ia64", //PROCESSOR_ARCHITECTURE_IA64
+            9 => b"x86_64", //PROCESSOR_ARCHITECTURE_AMD64
+            12 => b"aarch64", //PROCESSOR_ARCHITECTURE_ARM64
+            _ => b"",
+        };
+
+        let mut result = Self::new();
+        Self::copy_field(&mut result.sysname, b"Windows");
+        Self::copy_field(&mut result.machine, machine);
+        Some(result)
+    }
+}
+
+#[inline]
+///Returns time elapsed since boot, including time spent in sleep.
+pub fn uptime() -> Option<Duration> {
+    Some(Duration::from_millis(unsafe { GetTickCount64() }))
+}
+
+///Returns boot time as duration since Unix epoch.
+pub fn boot_time() -> Option<Duration> {
+    let now = unsafe {
+        let mut now = mem::MaybeUninit::<FILETIME>::uninit();
+        GetSystemTimeAsFileTime(now.as_mut_ptr());
+        now.assume_init()
+    };
+    //100-nanosecond intervals since 1601-01-01
+    let now = ((now.dwHighDateTime as u64) << 32) | now.dwLowDateTime as u64;
+    let now = Duration::new(now / 10_000_000, ((now % 10_000_000) * 100) as u32);
+
+    now.checked_sub(Duration::from_secs(UNIX_EPOCH_OFFSET))?.checked_sub(uptime()?)
+}
+
+#[inline(always)]
+///Returns system load averages.
+///
+///Not available on this platform.
+pub fn load_average() -> Option<LoadAverage> {
+    None
+}
@@ -1,38 +1,77 @@
 use core::ptr;
 
-use windows_sys::Win32::Globalization::{CP_UTF8, WideCharToMultiByte};
-use windows_sys::Win32::System::SystemInformation::{ComputerNamePhysicalDnsHostname, GetComputerNameExW};
+use windows_sys::Win32::Globalization::{CP_UTF8, WideCharToMultiByte, MultiByteToWideChar};
+use windows_sys::Win32::System::SystemInformation::{COMPUTER_NAME_FORMAT, ComputerNamePhysicalDnsHostname, ComputerNamePhysicalDnsFullyQualified, GetComputerNameExW, SetComputerNameExW};
 
 pub mod mem;
 pub mod cpu;
 pub mod network;
+pub mod host;
+pub mod numa;
 pub use crate::data::host::HostName;
 
+fn computer_name(format: COMPUTER_NAME_FORMAT) -> Option<HostName> {
+    let mut buff = core::mem::MaybeUninit::<[u16; HostName::capacity()]>::uninit();
+    let mut size = HostName::capacity();
+
+    let res = unsafe {
+        //retrieves size
+        GetComputerNameExW(format, buff.as_mut_ptr() as *mut u16, &mut size as *mut _ as *mut _)
+    };
+
+    if res == 0 {
+        return None;
+    } else if size == 0 {
+        return Some(HostName::new())
+    }
+
+    let mut name = [0u8; HostName::capacity()];
+    unsafe {
+        WideCharToMultiByte(CP_UTF8, 0,
+                            buff.as_mut_ptr() as *const u16,
+                            -1, name.as_mut_ptr(), name.len() as _,
+                            ptr::null(), ptr::null_mut());
+    }
+
+    Some(HostName::name(name))
+}
+
 impl HostName {
+    #[inline(always)]
     ///Retrieves host's name.
     pub fn get() -> Option<HostName> {
-        let mut buff = core::mem::MaybeUninit::<[u16; HostName::capacity()]>::uninit();
-        let mut size = HostName::capacity();
-
-        let res = unsafe {
-            //retrieves size
-            GetComputerNameExW(ComputerNamePhysicalDnsHostname, buff.as_mut_ptr() as *mut u16, &mut size as *mut _ as *mut _)
-        };
-
-        if res == 0 {
-            return None;
-        } else if size == 0 {
-            return Some(HostName::new())
-        }
+        computer_name(ComputerNamePhysicalDnsHostname)
+    }
 
-        let mut name = [0u8; HostName::capacity()];
+    #[inline(always)]
+    ///Retrieves host's fully qualified domain name.
+    pub fn fqdn() -> Option<HostName> {
+        computer_name(ComputerNamePhysicalDnsFullyQualified)
+    }
+
+    ///Sets host's name.
+    ///
+    ///Requires administrator privileges and takes effect after reboot.
+    ///Use `HostName::parse` to make sure name is valid before setting it.
+    ///
+    ///Returns `true` on success, otherwise please check `std::io::Error::last_os_error()`
+    pub fn set(name: &HostName) -> bool {
+        let name = name.as_bytes();
+        let mut buff = [0u16; HostName::capacity() + 1];
         unsafe {
-            WideCharToMultiByte(CP_UTF8, 0,
-                                buff.as_mut_ptr() as *const u16,
-                                -1, name.as_mut_ptr(), name.len() as _,
-                                ptr::null(), ptr::null_mut());
+            if !name.is_empty() && MultiByteToWideChar(CP_UTF8, 0, name.as_ptr(), name.len() as _, buff.as_mut_ptr(), HostName::capacity() as _) == 0 {
+                return false;
+            }
+
+            SetComputerNameExW(ComputerNamePhysicalDnsHostname, buff.as_ptr()) != 0
         }
+    }
 
-        Some(HostName::name(name))
+    #[inline(always)]
+    ///Retrieves NIS/YP domain name.
+    ///
+    ///Not available on this platform.
+    pub fn nis_domain() -> Option<HostName> {
+        None
     }
 }
@@ -15,6 +15,10 @@ use alloc::string::String;
 use alloc::borrow::Cow;
 
 pub use crate::data::network::{Ip, Address};
+use crate::data::network::diff_addresses;
+
+///Change between two [Interfaces](struct.Interfaces.html) snapshots.
+pub type Change<'a> = crate::data::network::Change<Interface<'a>>;
 
 impl Address {
     #[inline(always)]
@@ -77,6 +81,7 @@ impl Address {
 }
 
 ///Iterator over socket addresses
+#[derive(Clone)]
 pub struct Addresses<'a> {
     cursor: Option<&'a IP_ADAPTER_UNICAST_ADDRESS_LH>,
 }
@@ -115,6 +120,7 @@ impl<'a> Iterator for Addresses<'a> {
 }
 
 ///Interface
+#[derive(Clone, Copy)]
 pub struct Interface<'a> {
     addrs: &'a IP_ADAPTER_ADDRESSES_LH,
 }
@@ -279,6 +285,30 @@ impl Interfaces {
             cursor,
         }
     }
+
+    ///Computes list of changes required to get from `old` to `new` snapshot.
+    ///
+    ///Interfaces are matched by name, while addresses are matched by IP.
+    pub fn diff<'a>(old: &'a Interfaces, new: &'a Interfaces) -> Vec<Change<'a>> {
+        let mut result = Vec::new();
+
+        for old in old.iter() {
+            let old_name = old.name();
+            match new.iter().find(|new| new.name() == old_name) {
+                Some(new) => diff_addresses(old, old.addresses(), new, new.addresses(), &mut result),
+                None => result.push(Change::InterfaceRemoved(old)),
+            }
+        }
+
+        for new in new.iter() {
+            let new_name = new.name();
+            if !old.iter().any(|old| old.name() == new_name) {
+                result.push(Change::InterfaceAdded(new));
+            }
+        }
+
+        result
+    }
 }
 
 impl<'a> IntoIterator for &'a Interfaces {
@@ -0,0 +1,68 @@
+//! Helpers, shared by integration tests.
+#![allow(dead_code)]
+
+use std::path::{Path, PathBuf};
+
+///Temporary directory, used as root of fixture files, that is removed on drop.
+pub struct TempRoot {
+    path: PathBuf,
+}
+
+impl TempRoot {
+    ///Creates empty directory, unique to `name` and current process.
+    pub fn new(name: &str) -> Self {
+        let path = std::env::temp_dir().join(format!("system-info-{}-{}", name, std::process::id()));
+        let _ = std::fs::remove_dir_all(&path);
+        std::fs::create_dir_all(&path).expect("create root");
+
+        Self {
+            path
+        }
+    }
+
+    #[inline]
+    ///Returns full path of `path`, relative to root.
+    pub fn join(&self, path: &str) -> PathBuf {
+        self.path.join(path)
+    }
+
+    #[inline]
+    ///Returns root as string, accepted by `*_with_root` functions.
+    pub fn as_str(&self) -> &str {
+        self.path.to_str().expect("utf-8 temp dir")
+    }
+
+    #[inline]
+    ///Returns root's path.
+    pub fn path(&self) -> &Path {
+        &self.path
+    }
+
+    ///Creates directory at `path`, relative to root, including its parents.
+    pub fn create_dir(&self, path: &str) {
+        std::fs::create_dir_all(self.join(path)).expect("create dir");
+    }
+
+    ///Writes `content` into file at `path`, relative to root, creating parent directories.
+    pub fn write(&self, path: &str, content: impl AsRef<[u8]>) {
+        let path = self.join(path);
+        std::fs::create_dir_all(path.parent().expect("parent dir")).expect("create dir");
+        std::fs::write(path, content).expect("write file");
+    }
+}
+
+impl Drop for TempRoot {
+    #[inline]
+    fn drop(&mut self) {
+        let _ = std::fs::remove_dir_all(&self.path);
+    }
+}
+
+///Creates temporary root, containing `files` as pairs of relative path and content.
+pub fn fixture(name: &str, files: &[(&str, &str)]) -> TempRoot {
+    let root = TempRoot::new(name);
+    for (path, content) in files {
+        root.write(path, content);
+    }
+    root
+}
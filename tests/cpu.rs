@@ -1,6 +1,489 @@
 use system_info::cpu;
 
+mod common;
+
 #[test]
 fn should_get_cpu_num() {
     assert_ne!(cpu::count(), 0);
 }
+
+#[test]
+fn should_get_cpu_counts() {
+    let logical = cpu::logical_count();
+    let physical = cpu::physical_core_count();
+    let sockets = cpu::socket_count();
+    let online = cpu::online_count();
+    let configured = cpu::configured_count();
+    let available = cpu::available_count();
+
+    assert_ne!(sockets, 0);
+    assert!(sockets <= physical);
+    assert!(physical <= logical);
+    assert!(online <= logical);
+    assert!(logical <= configured);
+    assert!(available <= online);
+    assert_ne!(available, 0);
+}
+
+#[test]
+fn should_get_effective_parallelism() {
+    let parallelism = cpu::effective_parallelism();
+    assert_ne!(parallelism, 0);
+    assert!(parallelism <= cpu::available_count());
+}
+
+#[cfg(target_os = "linux")]
+#[test]
+fn should_read_cgroup_cpu_quota() {
+    use cpu::CpuQuota;
+
+    fn quota(name: &str, files: &[(&str, &str)]) -> Option<CpuQuota> {
+        let root = common::fixture(&format!("cgroup-{}", name), files);
+        CpuQuota::with_root(root.as_str())
+    }
+
+    const V2_MOUNTINFO: &str = "24 1 0:22 / /sys/fs/cgroup rw,nosuid - cgroup2 cgroup2 rw,nsdelegate\n";
+    const V2_CGROUP: &str = "0::/kubepods.slice/pod1/cri-abc\n";
+
+    assert_eq!(quota("none", &[]), None);
+    assert_eq!(quota("v2-unlimited", &[
+        ("proc/self/mountinfo", V2_MOUNTINFO),
+        ("proc/self/cgroup", V2_CGROUP),
+        ("sys/fs/cgroup/kubepods.slice/pod1/cri-abc/cpu.max", "max 100000\n"),
+    ]), None);
+
+    let limit = quota("v2", &[
+        ("proc/self/mountinfo", V2_MOUNTINFO),
+        ("proc/self/cgroup", V2_CGROUP),
+        ("sys/fs/cgroup/kubepods.slice/cpu.max", "max 100000\n"),
+        ("sys/fs/cgroup/kubepods.slice/pod1/cpu.max", "250000 100000\n"),
+        ("sys/fs/cgroup/kubepods.slice/pod1/cri-abc/cpu.max", "max 100000\n"),
+    ]).expect("get v2 quota");
+    assert_eq!(limit, CpuQuota { quota: 250_000, period: 100_000 });
+    assert_eq!(limit.cpus(), 3);
+
+    let limit = quota("v2-nested", &[
+        ("proc/self/mountinfo", V2_MOUNTINFO),
+        ("proc/self/cgroup", V2_CGROUP),
+        ("sys/fs/cgroup/kubepods.slice/pod1/cpu.max", "400000 100000\n"),
+        ("sys/fs/cgroup/kubepods.slice/pod1/cri-abc/cpu.max", "150000 100000\n"),
+    ]).expect("get nested v2 quota");
+    assert_eq!(limit.cpus(), 2);
+
+    //Container's view: own cgroup is mounted as root of hierarchy
+    let limit = quota("v2-namespace", &[
+        ("proc/self/mountinfo", "1200 1190 0:22 /kubepods.slice/pod1/cri-abc /sys/fs/cgroup ro - cgroup2 cgroup2 rw\n"),
+        ("proc/self/cgroup", "0::/\n"),
+        ("sys/fs/cgroup/cpu.max", "20000 100000\n"),
+    ]).expect("get namespaced v2 quota");
+    assert_eq!(limit.cpus(), 1);
+
+    let limit = quota("v1", &[
+        ("proc/self/mountinfo", concat!(
+            "32 24 0:28 / /sys/fs/cgroup rw,relatime - tmpfs tmpfs rw,mode=755\n",
+            "33 32 0:29 /docker/abc /sys/fs/cgroup/cpu,cpuacct rw,relatime shared:12 - cgroup cgroup rw,cpu,cpuacct\n",
+            "34 32 0:30 / /sys/fs/cgroup/unified rw,relatime - cgroup2 cgroup2 rw\n",
+        )),
+        ("proc/self/cgroup", "4:cpu,cpuacct:/docker/abc\n1:name=systemd:/docker/abc\n0::/docker/abc\n"),
+        ("sys/fs/cgroup/cpu,cpuacct/cpu.cfs_quota_us", "150000\n"),
+        ("sys/fs/cgroup/cpu,cpuacct/cpu.cfs_period_us", "100000\n"),
+    ]).expect("get v1 quota");
+    assert_eq!(limit, CpuQuota { quota: 150_000, period: 100_000 });
+    assert_eq!(limit.cpus(), 2);
+
+    assert_eq!(quota("v1-unlimited", &[
+        ("proc/self/mountinfo", "33 32 0:29 / /sys/fs/cgroup/my\\040cpu rw - cgroup cgroup rw,cpu\n"),
+        ("proc/self/cgroup", "4:cpu:/user.slice\n"),
+        ("sys/fs/cgroup/my cpu/user.slice/cpu.cfs_quota_us", "-1\n"),
+        ("sys/fs/cgroup/my cpu/user.slice/cpu.cfs_period_us", "100000\n"),
+    ]), None);
+    assert_eq!(quota("v1-escaped", &[
+        ("proc/self/mountinfo", "33 32 0:29 / /sys/fs/cgroup/my\\040cpu rw - cgroup cgroup rw,cpu\n"),
+        ("proc/self/cgroup", "4:cpu:/user.slice\n"),
+        ("sys/fs/cgroup/my cpu/user.slice/cpu.cfs_quota_us", "100000\n"),
+        ("sys/fs/cgroup/my cpu/user.slice/cpu.cfs_period_us", "100000\n"),
+    ]).map(|quota| quota.cpus()), Some(1));
+}
+
+#[test]
+fn should_handle_cpu_set() {
+    use cpu::CpuSet;
+
+    let mut set = CpuSet::parse("0-3,8,10-11\n").expect("parse cpu list");
+    assert_eq!(set.len(), 7);
+    assert_eq!(set.iter().collect::<Vec<_>>(), [0, 1, 2, 3, 8, 10, 11]);
+    assert_eq!(set.to_string(), "0-3,8,10-11");
+    assert!(set.contains(8));
+    assert!(!set.contains(9));
+    assert!(!set.contains(100_000));
+
+    assert!(set.insert(9));
+    assert!(!set.insert(9));
+    assert_eq!(set.to_string(), "0-3,8-11");
+    assert!(set.remove(0));
+    assert!(!set.remove(0));
+    assert!(!set.remove(100_000));
+    assert_eq!(set.to_string(), "1-3,8-11");
+
+    //Beyond CPU_SETSIZE
+    assert!(set.insert(4095));
+    assert_eq!(set.to_string(), "1-3,8-11,4095");
+    assert!(set.remove(4095));
+    assert_eq!(set, CpuSet::parse("1-3,8-11").expect("parse cpu list"));
+    assert_eq!(format!("{:?}", set), "CpuSet(1-3,8-11)");
+
+    assert_eq!(CpuSet::parse(" ").expect("parse empty list"), CpuSet::new());
+    assert!(CpuSet::new().is_empty());
+    assert_eq!(CpuSet::new().to_string(), "");
+    assert_eq!([63usize, 64, 65, 127, 128].iter().copied().collect::<CpuSet>().to_string(), "63-65,127-128");
+    assert!(CpuSet::parse("3-1").is_none());
+    assert!(CpuSet::parse("1,,2").is_none());
+    assert!(CpuSet::parse("a-b").is_none());
+    assert!(CpuSet::parse("0-18446744073709551615").is_none());
+    assert!(CpuSet::parse(&format!("0,{}", CpuSet::MAX_CPUS)).is_none());
+    assert_eq!(CpuSet::parse(&format!("{}", CpuSet::MAX_CPUS - 1)).expect("parse last cpu").len(), 1);
+
+    assert!(!set.insert(usize::MAX));
+    assert!(!set.insert(CpuSet::MAX_CPUS));
+    assert!(!set.contains(CpuSet::MAX_CPUS));
+    assert_eq!(set.to_string(), "1-3,8-11");
+    let last = (CpuSet::MAX_CPUS - 1..CpuSet::MAX_CPUS + 64).chain(Some(usize::MAX)).collect::<CpuSet>();
+    assert_eq!(last.iter().collect::<Vec<_>>(), [CpuSet::MAX_CPUS - 1]);
+
+    set.clear();
+    assert!(set.is_empty());
+}
+
+#[cfg(target_os = "linux")]
+#[test]
+fn should_get_and_set_affinity() {
+    let cpus = cpu::affinity(0).unwrap_or_else(|| panic!("Cannot get affinity: {}", std::io::Error::last_os_error()));
+    assert_eq!(cpus.len(), cpu::available_count());
+
+    assert!(cpu::affinity(std::process::id()).is_some());
+    assert!(cpu::affinity(u32::MAX >> 1).is_none());
+
+    //Run in separate thread to avoid affecting other tests
+    std::thread::spawn(move || {
+        let first = cpus.iter().next().expect("first cpu");
+        let single = core::iter::once(first).collect();
+        assert!(cpu::set_affinity(0, &single), "Cannot set affinity: {}", std::io::Error::last_os_error());
+        assert_eq!(cpu::affinity(0).expect("get affinity"), single);
+
+        assert!(cpu::set_affinity(0, &cpus));
+        assert_eq!(cpu::affinity(0).expect("get affinity"), cpus);
+
+        assert!(!cpu::set_affinity(0, &cpu::CpuSet::new()));
+    }).join().expect("run affinity thread");
+}
+
+#[cfg(target_os = "linux")]
+#[test]
+fn should_parse_cpu_stats() {
+    use core::time::Duration;
+    use cpu::{Stats, Times};
+
+    const PROC_STAT: &[u8] = b"cpu  10132153 290696 3084719 46828483 16683 0 25195 0 175628 0
+cpu0 1393280 32966 572056 13343292 6130 0 17875 0 23933 0
+cpu2 1335150 31548 584810 13373455 5063 0 1245 50 0 0
+intr 199292311 24 0 0 0
+ctxt 223497932
+btime 1713780513
+processes 2095421
+procs_running 3
+procs_blocked 1
+softirq 93758419 1 30305154 0
+";
+
+    let stats = Stats::parse(PROC_STAT, 100).expect("parse stats");
+    assert_eq!(stats.total.user, Duration::from_millis(101_321_530));
+    assert_eq!(stats.total.guest, Duration::from_millis(1_756_280));
+    assert_eq!(stats.cpus.len(), 2);
+    assert_eq!(stats.cpus[1].0, 2);
+    assert!(stats.cpu(1).is_none());
+
+    let cpu2 = stats.cpu(2).expect("get cpu2");
+    assert_eq!(cpu2.steal, Duration::from_millis(500));
+    assert_eq!(cpu2.total(), Duration::from_millis(10 * (1335150 + 31548 + 584810 + 13373455 + 5063 + 1245 + 50)));
+    assert_eq!(cpu2.busy(), Duration::from_millis(10 * (1335150 + 31548 + 584810 + 1245 + 50)));
+    assert_eq!(stats.ctxt, 223497932);
+    assert_eq!(stats.processes, 2095421);
+    assert_eq!(stats.procs_running, 3);
+    assert_eq!(stats.procs_blocked, 1);
+
+    //Non-default ticks and kernels without newer fields
+    let stats = Stats::parse(b"cpu 3 0 1 7\n", 3).expect("parse old format");
+    assert_eq!(stats.total, Times {
+        user: Duration::from_secs(1),
+        system: Duration::from_nanos(333_333_333),
+        idle: Duration::new(2, 333_333_333),
+        ..Times::default()
+    });
+    assert!(stats.cpus.is_empty());
+
+    assert!(Stats::parse(b"cpu0 1 2 3 4\n", 100).is_none());
+    assert!(Stats::parse(b"cpu 1 2 3\n", 100).is_none());
+    assert!(Stats::parse(b"cpu 1 2 3 x\n", 100).is_none());
+
+    let stats = Stats::new().expect("read stats");
+    assert_eq!(stats.cpus.len(), cpu::online_count());
+    assert!(stats.processes > 0);
+}
+
+#[cfg(target_os = "linux")]
+#[test]
+fn should_sample_cpu_usage() {
+    use cpu::{Stats, Usage, UsageSampler};
+
+    fn assert_usage(usage: &Usage, user: f64, system: f64, iowait: f64, steal: f64, idle: f64) {
+        let actual = [usage.user, usage.system, usage.iowait, usage.steal, usage.idle];
+        let expected = [user, system, iowait, steal, idle];
+        for (actual, expected) in actual.iter().zip(expected.iter()) {
+            assert!((actual - expected).abs() < 0.0001, "{:?} != {:?}", usage, expected);
+        }
+    }
+
+    let stats = |content: &str| Stats::parse(content.as_bytes(), 100).expect("parse stats");
+
+    let mut sampler = UsageSampler::with_stats(stats("cpu 100 0 100 800 0 0 0 0 0 0
+cpu0 50 0 50 400 0 0 0 0 0 0
+cpu1 50 0 50 400 0 0 0 0 0 0
+"));
+    let usage = sampler.update(stats("cpu 170 10 130 860 20 5 5 0 0 0
+cpu0 110 10 70 400 0 5 5 0 0 0
+cpu1 60 0 60 460 20 0 0 0 0 0
+"));
+    assert_usage(usage.cpu(0).expect("cpu0"), 70.0, 30.0, 0.0, 0.0, 0.0);
+    assert_usage(usage.cpu(1).expect("cpu1"), 10.0, 10.0, 20.0, 0.0, 60.0);
+    assert_usage(&usage.total, 40.0, 20.0, 10.0, 0.0, 30.0);
+    assert!((usage.total.busy() - 60.0).abs() < 0.0001);
+
+    //cpu1 goes offline, cpu2 comes online: aggregate must not be skewed
+    let usage = sampler.update(stats("cpu 200 10 130 540 0 5 5 10 0 0
+cpu0 150 10 70 440 0 5 5 10 0 0
+cpu2 0 0 0 100 0 0 0 0 0 0
+"));
+    assert_eq!(usage.cpus.len(), 1);
+    assert!(usage.cpu(2).is_none());
+    assert_usage(usage.cpu(0).expect("cpu0"), 44.4444, 0.0, 0.0, 11.1111, 44.4444);
+    assert_eq!(usage.total, *usage.cpu(0).expect("cpu0"));
+
+    //Counter reset
+    let usage = sampler.update(stats("cpu 10 0 10 10 0 0 0 0 0 0
+cpu0 10 0 10 10 0 0 0 0 0 0
+"));
+    assert_eq!(usage.total, Usage::default());
+
+    //No per-CPU lines
+    let mut sampler = UsageSampler::with_stats(stats("cpu 0 0 0 0\n"));
+    let usage = sampler.update(stats("cpu 25 25 25 25\n"));
+    assert!(usage.cpus.is_empty());
+    assert_usage(&usage.total, 50.0, 25.0, 0.0, 0.0, 25.0);
+
+    let mut sampler = UsageSampler::new().expect("create sampler");
+    std::thread::sleep(core::time::Duration::from_millis(50));
+    let usage = sampler.sample().expect("sample usage");
+    assert!(usage.total.busy() <= 100.0 + 0.0001);
+}
+
+#[cfg(target_os = "linux")]
+#[test]
+fn should_get_cpu_info() {
+    use cpu::{Info, Feature};
+
+    const X86_CPUINFO: &[u8] = b"processor\t: 0
+vendor_id\t: GenuineIntel
+cpu family\t: 6
+model\t\t: 158
+model name\t: Intel(R) Core(TM) i7-8700 CPU @ 3.20GHz
+stepping\t: 10
+microcode\t: 0xf4
+flags\t\t: fpu sse sse2 pni ssse3 sse4_1 sse4_2 avx avx2 fma
+
+processor\t: 1
+vendor_id\t: GenuineIntel
+cpu family\t: 6
+model\t\t: 158
+stepping\t: 10
+flags\t\t: fpu sse sse2
+";
+
+    const ARM_CPUINFO: &[u8] = b"processor\t: 0
+BogoMIPS\t: 50.00
+Features\t: fp asimd evtstrm aes pmull sha1 sha2 crc32 atomics fphp asimdhp cpuid asimdrdm lrcpc dcpop asimddp
+CPU implementer\t: 0x41
+CPU architecture: 8
+CPU variant\t: 0x3
+CPU part\t: 0xd0c
+CPU revision\t: 1
+
+processor\t: 4
+Features\t: fp asimd
+CPU implementer\t: 0x51
+CPU architecture: 8
+CPU variant\t: 0xa
+CPU part\t: 0x801
+CPU revision\t: 4
+";
+
+    let infos = Info::parse(X86_CPUINFO);
+    assert_eq!(infos.len(), 2);
+    let info = &infos[0];
+    assert_eq!(info.cpu, 0);
+    assert_eq!(info.vendor.as_deref(), Some("GenuineIntel"));
+    assert_eq!(info.model_name.as_deref(), Some("Intel(R) Core(TM) i7-8700 CPU @ 3.20GHz"));
+    assert_eq!((info.family, info.model, info.stepping), (6, 158, 10));
+    assert_eq!(info.microcode, Some(0xf4));
+    assert!(info.has_feature(Feature::Avx2));
+    assert!(info.has_feature(Feature::Sse3));
+    assert!(!info.has_feature(Feature::Avx512f));
+    assert!(info.has_flag("fma"));
+    assert_eq!(info.flags().count(), 10);
+    assert_eq!(infos[1].cpu, 1);
+    assert_eq!(infos[1].model_name, None);
+    assert!(!infos[1].has_feature(Feature::Avx2));
+
+    let infos = Info::parse(ARM_CPUINFO);
+    assert_eq!(infos.len(), 2);
+    let info = &infos[0];
+    assert_eq!(info.vendor.as_deref(), Some("ARM"));
+    assert_eq!((info.implementer, info.variant), (Some(0x41), Some(3)));
+    assert_eq!((info.family, info.model, info.stepping), (8, 0xd0c, 1));
+    assert!(info.has_flag("asimd"));
+    assert!(info.has_feature(Feature::Aes));
+    assert!(info.has_feature(Feature::DotProd));
+    assert!(!info.has_feature(Feature::Sve));
+    assert_eq!(infos[1].cpu, 4);
+    assert_eq!(infos[1].vendor.as_deref(), Some("Qualcomm"));
+    assert_eq!(infos[1].model, 0x801);
+
+    assert!(Info::parse(b"").is_empty());
+
+    let info = cpu::info().expect("get cpu info");
+    let infos = cpu::info_all().expect("read cpuinfo");
+    assert_eq!(infos.len(), cpu::online_count());
+
+    #[cfg(any(target_arch = "x86", target_arch = "x86_64"))]
+    {
+        let cpuinfo = infos.iter().find(|cpuinfo| cpuinfo.cpu == info.cpu).expect("find cpu");
+        assert_eq!(info.vendor, cpuinfo.vendor);
+        assert_eq!(info.model_name, cpuinfo.model_name);
+        assert_eq!((info.family, info.model, info.stepping), (cpuinfo.family, cpuinfo.model, cpuinfo.stepping));
+        assert_eq!(info.microcode, cpuinfo.microcode);
+    }
+}
+
+#[cfg(any(target_arch = "x86", target_arch = "x86_64"))]
+#[test]
+fn should_detect_cpu_features() {
+    use cpu::Feature;
+
+    let info = cpu::info().expect("get cpu info");
+    assert_eq!(cpu::has_feature(Feature::Sse2), std::is_x86_feature_detected!("sse2"));
+    assert_eq!(cpu::has_feature(Feature::Sse42), std::is_x86_feature_detected!("sse4.2"));
+    assert_eq!(cpu::has_feature(Feature::Avx), std::is_x86_feature_detected!("avx"));
+    assert_eq!(cpu::has_feature(Feature::Avx2), std::is_x86_feature_detected!("avx2"));
+    assert_eq!(cpu::has_feature(Feature::Fma), std::is_x86_feature_detected!("fma"));
+    assert_eq!(cpu::has_feature(Feature::Bmi2), std::is_x86_feature_detected!("bmi2"));
+    assert_eq!(cpu::has_feature(Feature::Lzcnt), std::is_x86_feature_detected!("lzcnt"));
+    assert_eq!(cpu::has_feature(Feature::Avx512f), std::is_x86_feature_detected!("avx512f"));
+    assert_eq!(cpu::has_feature(Feature::Sha), std::is_x86_feature_detected!("sha"));
+
+    //Cached single leaf check must agree with full identification
+    for feature in [Feature::Sse3, Feature::Ssse3, Feature::Popcnt, Feature::Aes, Feature::F16c, Feature::Avx512bw, Feature::Vaes, Feature::Hypervisor, Feature::Neon] {
+        assert_eq!(cpu::has_feature(feature), info.has_feature(feature), "{:?}", feature);
+        assert_eq!(cpu::has_feature(feature), info.has_feature(feature), "{:?}", feature);
+    }
+}
+
+#[cfg(target_os = "linux")]
+#[test]
+fn should_get_cpu_caches() {
+    use cpu::{CacheType, CpuSet};
+
+    fn write_index(root: &common::TempRoot, cpu: usize, index: usize, values: &[(&str, &str)]) {
+        for (name, value) in values {
+            root.write(&format!("sys/devices/system/cpu/cpu{}/cache/index{}/{}", cpu, index, name), format!("{}\n", value));
+        }
+    }
+
+    let root = common::fixture("cache", &[("sys/devices/system/cpu/online", "0-3\n")]);
+
+    //Two CCX, each with 2 CPUs sharing L3 and private L1/L2
+    for cpu in 0..4 {
+        let own = cpu.to_string();
+        let ccx = match cpu < 2 {
+            true => "0-1",
+            false => "2-3",
+        };
+        write_index(&root, cpu, 0, &[("level", "1"), ("type", "Data"), ("size", "32K"), ("coherency_line_size", "64"), ("ways_of_associativity", "8"), ("shared_cpu_list", &own)]);
+        write_index(&root, cpu, 1, &[("level", "1"), ("type", "Instruction"), ("size", "32K"), ("coherency_line_size", "64"), ("ways_of_associativity", "8"), ("shared_cpu_list", &own)]);
+        write_index(&root, cpu, 2, &[("level", "2"), ("type", "Unified"), ("size", "512K"), ("coherency_line_size", "64"), ("ways_of_associativity", "8"), ("shared_cpu_list", &own)]);
+        write_index(&root, cpu, 3, &[("level", "3"), ("type", "Unified"), ("size", "16M"), ("coherency_line_size", "64"), ("ways_of_associativity", "0"), ("shared_cpu_list", ccx)]);
+    }
+
+    let caches = cpu::caches_with_root(root.as_str()).expect("read caches");
+
+    assert_eq!(caches.len(), 4 * 3 + 2);
+    assert_eq!(caches[0].level, 1);
+    assert_eq!(caches[0].kind, CacheType::Data);
+    assert_eq!(caches[0].size, 32 * 1024);
+    assert_eq!(caches[0].line_size, 64);
+    assert_eq!(caches[0].associativity, Some(8));
+    assert_eq!(caches[0].cpus, CpuSet::parse("0").unwrap());
+    assert_eq!(caches[4].kind, CacheType::Instruction);
+
+    let l3 = caches.iter().filter(|cache| cache.level == 3).collect::<Vec<_>>();
+    assert_eq!(l3.len(), 2);
+    assert_eq!(l3[0].size, 16 * 1024 * 1024);
+    assert_eq!(l3[0].associativity, None);
+    assert_eq!(l3[0].cpus, CpuSet::parse("0-1").unwrap());
+    assert_eq!(l3[1].cpus, CpuSet::parse("2-3").unwrap());
+
+    assert!(cpu::caches_with_root("/non-existent").is_none());
+
+    if let Some(caches) = cpu::caches() {
+        for cache in caches.iter() {
+            assert_ne!(cache.level, 0);
+            assert!(!cache.cpus.is_empty());
+        }
+    }
+}
+
+#[cfg(target_os = "linux")]
+#[test]
+fn should_get_cpu_frequency() {
+    let root = common::fixture("freq", &[
+        ("sys/devices/system/cpu/cpu0/cpufreq/scaling_cur_freq", "1800000\n"),
+        ("sys/devices/system/cpu/cpu0/cpufreq/scaling_min_freq", "800000\n"),
+        ("sys/devices/system/cpu/cpu0/cpufreq/scaling_max_freq", "3600000\n"),
+        ("sys/devices/system/cpu/cpu0/cpufreq/scaling_governor", "schedutil\n"),
+        ("sys/devices/system/cpu/cpu0/cpufreq/scaling_driver", "acpi-cpufreq\n"),
+        ("sys/devices/system/cpu/cpu0/cpufreq/scaling_available_governors", "conservative ondemand userspace powersave performance schedutil\n"),
+        ("sys/devices/system/cpu/cpu0/cpufreq/scaling_available_frequencies", "3600000 2800000 2200000 800000\n"),
+        ("proc/cpuinfo", "processor\t: 0\ncpu MHz\t\t: 2100.000\n\nprocessor\t: 1\ncpu MHz\t\t: 2400.500\n\n"),
+    ]);
+
+    let scaled = cpu::frequency_with_root(root.as_str(), 0);
+    let fallback = cpu::frequency_with_root(root.as_str(), 1);
+    let missing = cpu::frequency_with_root(root.as_str(), 2);
+
+    let scaled = scaled.expect("read cpufreq");
+    assert!(scaled.is_scaled());
+    assert_eq!(scaled.current, Some(1_800_000));
+    assert_eq!(scaled.min, Some(800_000));
+    assert_eq!(scaled.max, Some(3_600_000));
+    assert_eq!(scaled.governor.as_deref(), Some("schedutil"));
+    assert_eq!(scaled.driver.as_deref(), Some("acpi-cpufreq"));
+    assert_eq!(scaled.available_governors.len(), 6);
+    assert_eq!(scaled.available_frequencies, [3_600_000, 2_800_000, 2_200_000, 800_000]);
+
+    let fallback = fallback.expect("read cpuinfo");
+    assert!(!fallback.is_scaled());
+    assert_eq!(fallback.current, Some(2_400_500));
+    assert_eq!(fallback.max, None);
+    assert!(fallback.available_frequencies.is_empty());
+
+    assert!(missing.is_none());
+}
+
@@ -1,5 +1,7 @@
 use system_info::HostName;
 
+mod common;
+
 #[test]
 fn should_get_host_name() {
     let expected = std::process::Command::new("hostname").output().expect("Get hostname");
@@ -14,3 +16,420 @@ fn should_get_host_name() {
     assert_eq!(name.as_bytes(), expected.as_bytes());
     assert_eq!(name.as_str(), Ok(expected));
 }
+
+#[cfg(unix)]
+#[test]
+fn should_get_uname() {
+    use system_info::host::Uname;
+
+    fn uname(arg: &str) -> String {
+        let output = std::process::Command::new("uname").arg(arg).output().expect("run uname");
+        String::from_utf8(output.stdout).expect("utf-8 uname").trim().to_owned()
+    }
+
+    let info = match Uname::get() {
+        Some(info) => info,
+        None => panic!("Cannot get uname: {}", std::io::Error::last_os_error()),
+    };
+    assert_eq!(info.sysname(), Ok(uname("-s").as_str()));
+    assert_eq!(info.release(), Ok(uname("-r").as_str()));
+    assert_eq!(info.machine(), Ok(uname("-m").as_str()));
+    assert!(!info.version().expect("utf-8 version").is_empty());
+    assert_ne!(info.domainname(), Ok("(none)"));
+}
+
+#[test]
+fn should_parse_os_release() {
+    use system_info::host::OsRelease;
+
+    const OS_RELEASE: &[u8] = b"# Comment\n\
+                                NAME=\"Ubuntu\"\n\
+                                VERSION_ID=\"22.04\"\n\
+                                ID=ubuntu\n\
+                                ID_LIKE='debian fedora'\n\
+                                PRETTY_NAME=\"Ubuntu \\\"Jammy\\\" \\$HOME \\\\ 22.04\"\n\
+                                VERSION_CODENAME=jam\\ my\n\
+                                \n\
+                                   BUILD_ID=  \"2022-04-01\"\n\
+                                VARIANT_ID='it'\\''s'\n\
+                                BROKEN=\"unterminated\n\
+                                invalid line\n\
+                                lower-case=value\n\
+                                ID=ubuntu-core\n";
+
+    let release = OsRelease::parse(OS_RELEASE);
+    assert_eq!(release.get("NAME"), Some("Ubuntu"));
+    assert_eq!(release.id(), "ubuntu-core");
+    assert_eq!(release.id_like().collect::<Vec<_>>(), ["debian", "fedora"]);
+    assert!(release.is_like("debian"));
+    assert!(!release.is_like("arch"));
+    assert_eq!(release.version_id(), Some("22.04"));
+    assert_eq!(release.pretty_name(), "Ubuntu \"Jammy\" $HOME \\ 22.04");
+    assert_eq!(release.version_codename(), Some("jam my"));
+    assert_eq!(release.build_id(), Some("2022-04-01"));
+    assert_eq!(release.variant_id(), Some("it's"));
+    assert_eq!(release.get("BROKEN"), None);
+    assert_eq!(release.get("lower-case"), None);
+    assert_eq!(release.iter().count(), 8);
+
+    let empty = OsRelease::parse(b"");
+    assert_eq!(empty.id(), "linux");
+    assert_eq!(empty.pretty_name(), "Linux");
+
+    #[cfg(target_os = "linux")]
+    {
+        let release = OsRelease::new().expect("read os-release");
+        assert!(!release.id().is_empty());
+    }
+}
+
+#[cfg(any(target_os = "linux", target_os = "android"))]
+#[test]
+fn should_get_uptime_and_boot_time() {
+    use system_info::host;
+
+    let uptime = host::uptime().expect("get uptime");
+    let boot_time = host::boot_time().expect("get boot time");
+    let now = std::time::SystemTime::now().duration_since(std::time::UNIX_EPOCH).expect("get time");
+
+    assert_ne!(uptime.as_secs_f64(), 0.0);
+    assert!(boot_time < now);
+    //btime is rounded to seconds
+    let diff = (now.as_secs_f64() - boot_time.as_secs_f64() - uptime.as_secs_f64()).abs();
+    assert!(diff < 5.0, "Boot time is off by {}s", diff);
+}
+
+#[test]
+fn should_parse_load_average() {
+    use system_info::host::{self, LoadAverage};
+
+    let load = LoadAverage::parse(b"0.20 1.18 12.50 3/801 11206\n").expect("parse loadavg");
+    assert_eq!(load.one, 0.20);
+    assert_eq!(load.five, 1.18);
+    assert_eq!(load.fifteen, 12.50);
+    assert_eq!(load.running, Some(3));
+    assert_eq!(load.total, Some(801));
+    assert_eq!(load.last_pid, Some(11206));
+
+    let load = LoadAverage::parse(b"0.00 0.01 0.05").expect("parse short loadavg");
+    assert_eq!(load.fifteen, 0.05);
+    assert_eq!(load.running, None);
+    assert_eq!(load.last_pid, None);
+
+    assert!(LoadAverage::parse(b"0.00 invalid").is_none());
+
+    #[cfg(unix)]
+    {
+        let load = host::load_average().expect("get load average");
+        assert!(load.one >= 0.0);
+        #[cfg(target_os = "linux")]
+        assert!(load.total.expect("get total tasks") >= load.running.expect("get running tasks"));
+    }
+    #[cfg(not(unix))]
+    let _ = host::load_average();
+}
+
+#[test]
+fn should_parse_id128() {
+    use system_info::host::Id128;
+
+    let id = Id128::parse(b"0123456789abcdef0123456789ABCDEF\n").expect("parse id");
+    assert_eq!(id.to_string(), "0123456789abcdef0123456789abcdef");
+    assert_eq!(id.as_bytes()[..2], [0x01, 0x23]);
+    let uuid = Id128::parse(b"01234567-89ab-cdef-0123-456789abcdef").expect("parse uuid");
+    assert_eq!(id, uuid);
+
+    assert!(Id128::parse(b"uninitialized").is_none());
+    assert!(Id128::parse(b"0123456789abcdef0123456789abcdeg").is_none());
+    assert!(Id128::parse(b"0123456789abcdef0123456789abcdef0").is_none());
+    assert!(Id128::parse(b"0123456789-abcdef-0123456789abcde").is_none());
+    assert!(Id128::parse(b"00000000000000000000000000000000").expect("parse null").is_null());
+
+    let machine_id = Id128::parse_machine_id(b"0123456789abcdef0123456789abcdef\n").expect("parse machine id");
+    assert_eq!(machine_id, id);
+    assert_eq!(Id128::parse_machine_id(b"0123456789abcdef0123456789abcdef"), Some(id));
+    assert!(Id128::parse_machine_id(b"01234567-89ab-cdef-0123-456789abcdef\n").is_none());
+    assert!(Id128::parse_machine_id(b"0123456789abcdef0123456789ABCDEF\n").is_none());
+    assert!(Id128::parse_machine_id(b" 0123456789abcdef0123456789abcdef\n").is_none());
+    assert!(Id128::parse_machine_id(b"0123456789abcdef0123456789abcdef\n\n").is_none());
+    assert!(Id128::parse_machine_id(b"uninitialized\n").is_none());
+
+    //Matches `systemd-id128 machine-id --app-specific` for the same machine ID
+    let app_id = Id128::parse(b"fedcba9876543210fedcba9876543210").expect("parse app id");
+    assert_eq!(id.app_specific(&app_id).to_string(), "64a4ee5da1a743238ba8b5418f428e41");
+    let id = Id128::new([0, 1, 2, 3, 4, 5, 6, 7, 8, 9, 10, 11, 12, 13, 14, 15]);
+    assert_eq!(id.app_specific(&Id128::new([0xaa; 16])).to_string(), "f9d99d01116d43bcab020c0165a0ae43");
+}
+
+#[cfg(any(target_os = "linux", target_os = "android"))]
+#[test]
+fn should_get_boot_id() {
+    use system_info::host;
+
+    let boot_id = host::boot_id().expect("get boot id");
+    let expected = std::fs::read_to_string("/proc/sys/kernel/random/boot_id").expect("read boot_id");
+    assert_eq!(boot_id.to_string(), expected.trim().replace('-', ""));
+
+    if let Ok(expected) = std::fs::read_to_string("/etc/machine-id") {
+        let machine_id = host::machine_id().expect("get machine id");
+        assert_eq!(machine_id.to_string(), expected.trim());
+    }
+}
+
+#[test]
+fn should_get_host_domain() {
+    fn host_name(name: &str) -> HostName {
+        let mut buffer = [0u8; HostName::capacity()];
+        buffer[..name.len()].copy_from_slice(name.as_bytes());
+        HostName::name(buffer)
+    }
+
+    assert_eq!(host_name("host.example.com").domain().expect("get domain"), "example.com");
+    assert!(host_name("host").domain().is_none());
+    assert!(host_name("host.").domain().is_none());
+
+    let fqdn = match HostName::fqdn() {
+        Some(fqdn) => fqdn,
+        None => return println!("Skip: cannot resolve fqdn of host name"),
+    };
+
+    //Canonical name may differ from host name (e.g. CNAME), so only its own parts are checked
+    let fqdn_str = fqdn.as_str().expect("utf-8 fqdn");
+    match fqdn_str.split_once('.') {
+        Some((_, domain)) if !domain.is_empty() => assert_eq!(fqdn.domain().expect("get domain"), domain),
+        _ => assert!(fqdn.domain().is_none()),
+    }
+}
+
+#[test]
+fn should_validate_host_name() {
+    use system_info::host::HostNameError;
+
+    assert_eq!(HostName::parse("host-1.example.com").expect("parse valid name"), "host-1.example.com");
+    assert_eq!(HostName::parse("a").expect("parse single char"), "a");
+    assert_eq!(HostName::parse("").unwrap_err(), HostNameError::Empty);
+    assert_eq!(HostName::parse(&"a.".repeat(128)).unwrap_err(), HostNameError::TooLong);
+    assert_eq!(HostName::parse("host..com").unwrap_err(), HostNameError::EmptyLabel);
+    assert_eq!(HostName::parse("host.").unwrap_err(), HostNameError::EmptyLabel);
+    assert_eq!(HostName::parse(&"a".repeat(64)).unwrap_err(), HostNameError::LabelTooLong);
+    assert!(HostName::parse(&"a".repeat(63)).is_ok());
+    assert_eq!(HostName::parse("-host").unwrap_err(), HostNameError::InvalidHyphen);
+    assert_eq!(HostName::parse("host-.com").unwrap_err(), HostNameError::InvalidHyphen);
+    assert_eq!(HostName::parse("host_name").unwrap_err(), HostNameError::InvalidCharacter);
+    assert_eq!(HostName::parse("host\0name").unwrap_err(), HostNameError::InvalidCharacter);
+    assert_eq!(HostName::parse("hôst").unwrap_err(), HostNameError::InvalidCharacter);
+}
+
+#[cfg(target_os = "linux")]
+#[test]
+fn should_set_host_name() {
+    const NAMESPACE_ENV: &str = "SYSTEM_INFO_TEST_UTSNS";
+    const TEST_FAILED_CODE: i32 = 101;
+    const NEW_NAME: &str = "system-info-test";
+
+    if std::env::var_os(NAMESPACE_ENV).is_none() {
+        //Re-run itself inside user and UTS namespace
+        let exe = std::env::current_exe().expect("get test executable");
+        let status = std::process::Command::new("unshare").arg("-Uru").arg(exe)
+                                                          .arg("--exact").arg("should_set_host_name").arg("--nocapture")
+                                                          .env(NAMESPACE_ENV, "1")
+                                                          .status();
+        match status {
+            Ok(status) if status.success() => (),
+            Ok(status) if status.code() == Some(TEST_FAILED_CODE) => panic!("Namespace test failed"),
+            Ok(_) => println!("Skip: cannot create UTS namespace"),
+            Err(error) => println!("Skip: cannot run unshare: {}", error),
+        }
+        return;
+    }
+
+    let name = HostName::parse(NEW_NAME).expect("parse name");
+    assert!(HostName::set(&name), "Cannot set hostname: {}", std::io::Error::last_os_error());
+    assert_eq!(HostName::get().expect("get hostname"), NEW_NAME);
+}
+
+#[cfg(target_os = "linux")]
+#[test]
+fn should_detect_virtualization() {
+    use system_info::host::{Virtualization, Hypervisor, Container};
+
+    fn detect(name: &str, files: &[(&str, &str)]) -> Virtualization {
+        let root = common::fixture(&format!("virt-{}", name), files);
+        Virtualization::with_root(root.as_str())
+    }
+
+    const OSRELEASE: (&str, &str) = ("proc/sys/kernel/osrelease", "6.1.0-13-amd64\n");
+    const HOST_CGROUP: (&str, &str) = ("proc/1/cgroup", "0::/init.scope\n");
+
+    let result = detect("bare", &[
+        OSRELEASE,
+        HOST_CGROUP,
+        ("sys/class/dmi/id/sys_vendor", "Dell Inc.\n"),
+        ("sys/class/dmi/id/product_name", "PowerEdge R640\n"),
+        ("proc/cpuinfo", "processor\t: 0\nflags\t\t: fpu vme de pse\n"),
+    ]);
+    assert_eq!(result, Virtualization { vm: Hypervisor::None, container: Container::None });
+    assert!(!result.is_vm());
+    assert!(!result.is_container());
+
+    assert_eq!(detect("kvm", &[("sys/class/dmi/id/product_name", "KVM\n")]).vm, Hypervisor::Kvm);
+    assert_eq!(detect("qemu", &[("sys/class/dmi/id/sys_vendor", "QEMU\n")]).vm, Hypervisor::Qemu);
+    assert_eq!(detect("vmware", &[("sys/class/dmi/id/sys_vendor", "VMware, Inc.\n")]).vm, Hypervisor::Vmware);
+    assert_eq!(detect("vbox", &[("sys/class/dmi/id/product_name", "VirtualBox\n")]).vm, Hypervisor::VirtualBox);
+    assert_eq!(detect("nitro", &[
+        ("sys/class/dmi/id/product_name", "m5.large\n"),
+        ("sys/class/dmi/id/sys_vendor", "Amazon EC2\n"),
+    ]).vm, Hypervisor::AwsNitro);
+    assert_eq!(detect("hyperv", &[
+        ("sys/class/dmi/id/product_name", "Virtual Machine\n"),
+        ("sys/class/dmi/id/sys_vendor", "Microsoft Corporation\n"),
+    ]).vm, Hypervisor::HyperV);
+    assert_eq!(detect("xen", &[("sys/hypervisor/type", "xen\n")]).vm, Hypervisor::Xen);
+    assert_eq!(detect("arm-kvm", &[("proc/device-tree/hypervisor/compatible", "linux,kvm\0")]).vm, Hypervisor::Kvm);
+    assert_eq!(detect("other", &[("proc/cpuinfo", "flags\t\t: fpu hypervisor lahf_lm\n")]).vm, Hypervisor::Other);
+
+    assert_eq!(detect("docker", &[OSRELEASE, HOST_CGROUP, (".dockerenv", "")]).container, Container::Docker);
+    assert_eq!(detect("podman", &[OSRELEASE, HOST_CGROUP, ("run/.containerenv", "")]).container, Container::Podman);
+    assert_eq!(detect("nspawn", &[OSRELEASE, ("run/systemd/container", "systemd-nspawn\n")]).container, Container::SystemdNspawn);
+    assert_eq!(detect("lxc", &[OSRELEASE, ("proc/1/environ", "PATH=/bin\0container=lxc\0")]).container, Container::Lxc);
+    assert_eq!(detect("lxc-cgroup", &[OSRELEASE, ("proc/1/cgroup", "0::/lxc.payload.web/init.scope\n")]).container, Container::Lxc);
+    assert_eq!(detect("k8s", &[
+        OSRELEASE,
+        (".dockerenv", ""),
+        ("proc/1/cgroup", "12:memory:/kubepods/burstable/pod1234/abcdef\n"),
+    ]).container, Container::Kubernetes);
+    assert_eq!(detect("wsl", &[("proc/sys/kernel/osrelease", "5.15.90.1-microsoft-standard-WSL2\n")]).container, Container::Wsl);
+}
+
+#[cfg(target_os = "linux")]
+#[test]
+fn should_read_dmi() {
+    use std::os::unix::fs::PermissionsExt;
+    use system_info::host::{Dmi, DmiError, ChassisType};
+
+    let root = common::TempRoot::new("dmi");
+    assert!(Dmi::with_root(root.as_str()).is_none());
+
+    let dir = root.join("sys/class/dmi/id");
+    for (name, content) in [
+        ("sys_vendor", "Dell Inc.\n"),
+        ("product_name", "PowerEdge R640\n"),
+        ("product_version", "\n"),
+        ("product_serial", "ABC1234\n"),
+        ("product_uuid", "4c4c4544-0042-3510-8052-b4c04f335a32\n"),
+        ("board_vendor", "Dell Inc.\n"),
+        ("board_name", "0W23H8\n"),
+        ("bios_vendor", "Dell Inc.\n"),
+        ("bios_version", "2.12.2\n"),
+        ("chassis_type", "23\n"),
+    ].iter() {
+        root.write(&format!("sys/class/dmi/id/{}", name), content);
+    }
+    std::fs::set_permissions(dir.join("product_serial"), std::fs::Permissions::from_mode(0o000)).expect("restrict serial");
+
+    let dmi = Dmi::with_root(root.as_str()).expect("read dmi");
+    assert_eq!(dmi.sys_vendor.as_deref(), Ok("Dell Inc."));
+    assert_eq!(dmi.product_name.as_deref(), Ok("PowerEdge R640"));
+    assert_eq!(dmi.product_version, Err(DmiError::Unavailable));
+    assert_eq!(dmi.product_uuid.expect("parse uuid").to_string(), "4c4c4544004235108052b4c04f335a32");
+    assert_eq!(dmi.board_name.as_deref(), Ok("0W23H8"));
+    assert_eq!(dmi.bios_version.as_deref(), Ok("2.12.2"));
+    assert_eq!(dmi.bios_date, Err(DmiError::Unavailable));
+    assert_eq!(dmi.chassis_type, Ok(ChassisType::RackMount));
+    assert_eq!(ChassisType::from_value(0x89), ChassisType::Laptop);
+    assert_eq!(ChassisType::from_value(100), ChassisType::Unrecognized(100));
+
+    //Privileged user bypasses file permissions
+    match std::fs::read(dir.join("product_serial")) {
+        Ok(_) => assert_eq!(dmi.product_serial.as_deref(), Ok("ABC1234")),
+        Err(_) => assert_eq!(dmi.product_serial, Err(DmiError::PermissionDenied)),
+    }
+    assert_eq!(DmiError::PermissionDenied.to_string(), "permission denied");
+}
+
+#[cfg(target_os = "linux")]
+#[test]
+fn should_get_time_info() {
+    use system_info::host::{time_info, TimeInfo};
+
+    let root = common::TempRoot::new("time");
+    root.create_dir("etc");
+    assert_eq!(TimeInfo::with_root(root.as_str()), TimeInfo {
+        timezone: None,
+        locale: None,
+        utc_offset: None,
+        clock: None,
+    });
+
+    root.write("etc/timezone", "Etc/UTC\n");
+    root.write("etc/default/locale", "LANG=\"C.UTF-8\"\n");
+    let info = TimeInfo::with_root(root.as_str());
+    assert_eq!(info.timezone.as_deref(), Some("Etc/UTC"));
+    assert_eq!(info.locale.as_deref(), Some("C.UTF-8"));
+
+    std::os::unix::fs::symlink("../usr/share/zoneinfo/right/Europe/Berlin", root.join("etc/localtime")).expect("link localtime");
+    root.write("etc/locale.conf", "# Generated\nLANG=de_DE.UTF-8\nLC_TIME=C\n");
+    let info = TimeInfo::with_root(root.as_str());
+    assert_eq!(info.timezone.as_deref(), Some("Europe/Berlin"));
+    assert_eq!(info.locale.as_deref(), Some("de_DE.UTF-8"));
+
+    let info = time_info();
+    let offset = info.utc_offset.expect("get utc offset");
+    assert!(offset.abs() <= 14 * 60 * 60);
+    assert!(info.clock.is_some(), "Cannot get clock status: {}", std::io::Error::last_os_error());
+}
+
+#[cfg(target_os = "linux")]
+#[test]
+fn should_read_pressure() {
+    use core::time::Duration;
+    use system_info::host::{self, Pressure, Resource, PsiError, Trigger, StallKind};
+
+    let pressure = Pressure::parse(b"some avg10=1.53 avg60=0.87 avg300=0.30 total=13212738\nfull avg10=0.00 avg60=0.01 avg300=0.00 total=52631\n").expect("parse pressure");
+    let some = pressure.some.expect("some");
+    assert_eq!(some.avg10, 1.53);
+    assert_eq!(some.avg60, 0.87);
+    assert_eq!(some.avg300, 0.30);
+    assert_eq!(some.total, Duration::from_micros(13212738));
+    assert_eq!(pressure.full.expect("full").total, Duration::from_micros(52631));
+
+    let pressure = Pressure::parse(b"full avg10=0.12 avg60=0.05 avg300=0.01 total=98765\n").expect("parse irq pressure");
+    assert!(pressure.some.is_none());
+    assert_eq!(pressure.full.expect("full").avg10, 0.12);
+
+    assert!(Pressure::parse(b"").is_none());
+    assert!(Pressure::parse(b"some avg10=x avg60=0.00 avg300=0.00 total=0\n").is_none());
+
+    let root = common::fixture("psi", &[
+        ("proc/pressure/memory", "some avg10=5.00 avg60=2.00 avg300=1.00 total=1000\nfull avg10=1.00 avg60=0.50 avg300=0.25 total=500\n"),
+        ("proc/pressure/io", "garbage\n"),
+        ("proc/self/cgroup", "0::/app.slice/service\n"),
+        ("proc/self/mountinfo", "24 1 0:22 / /sys/fs/cgroup rw,nosuid - cgroup2 cgroup2 rw,nsdelegate\n"),
+        ("sys/fs/cgroup/app.slice/service/cpu.pressure", "some avg10=42.00 avg60=10.00 avg300=3.00 total=777\nfull avg10=40.00 avg60=9.00 avg300=2.00 total=666\n"),
+    ]);
+
+    assert_eq!(Pressure::with_root(root.as_str(), Resource::Memory).expect("memory pressure").some.expect("some").avg10, 5.0);
+    assert_eq!(Pressure::with_root(root.as_str(), Resource::Io), Err(PsiError::Invalid));
+    assert_eq!(Pressure::with_root(root.as_str(), Resource::Cpu), Err(PsiError::Unsupported));
+    assert_eq!(Pressure::cgroup_with_root(root.as_str(), Resource::Cpu).expect("cgroup cpu pressure").full.expect("full").total, Duration::from_micros(666));
+    assert_eq!(Pressure::cgroup_with_root(root.as_str(), Resource::Io), Err(PsiError::Unsupported));
+
+    let pressure = host::pressure(Resource::Cpu);
+
+    let trigger = Trigger::new(Resource::Cpu, StallKind::Some, Duration::from_millis(100), Duration::from_secs(2));
+    match pressure {
+        Ok(_) => match trigger {
+            Ok(trigger) => {
+                assert!(trigger.fd() >= 0);
+                assert!(trigger.wait(Some(Duration::ZERO)).is_some());
+            },
+            //Unprivileged triggers are not supported by older kernels
+            Err(error) => assert_eq!(error, PsiError::PermissionDenied),
+        },
+        Err(PsiError::Unsupported) => assert_eq!(trigger.unwrap_err(), PsiError::Unsupported),
+        Err(error) => panic!("Unexpected error: {}", error),
+    }
+
+    let trigger = Trigger::new(Resource::Cpu, StallKind::Some, Duration::from_secs(3), Duration::from_secs(2));
+    assert!(trigger.is_err());
+}
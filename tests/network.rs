@@ -2,6 +2,8 @@ use system_info::network::Interfaces;
 
 use core::fmt::Write;
 
+mod common;
+
 #[test]
 fn should_print_network_interfaces() {
     let interfaces = match Interfaces::new() {
@@ -12,9 +14,196 @@ fn should_print_network_interfaces() {
     for interface in interfaces.iter() {
         let mut addrs_text = String::new();
         for addr in interface.addresses() {
-            let _ = write!(addrs_text, "addr={} net_mask={}\n", addr, addr.net_mask());
+            let _ = writeln!(addrs_text, "addr={} net_mask={}", addr, addr.net_mask());
         }
 
         println!("interface {:?}\n{}", interface.name(), addrs_text);
     }
 }
+
+#[test]
+fn should_diff_network_interfaces() {
+    let interfaces = match Interfaces::new() {
+        Some(interfaces) => interfaces,
+        None => panic!("Cannot get interfaces data {}", std::io::Error::last_os_error()),
+    };
+
+    let changes = Interfaces::diff(&interfaces, &interfaces);
+    assert!(changes.is_empty());
+}
+
+#[cfg(any(target_os = "linux", target_os = "android"))]
+#[test]
+fn should_get_network_sysctl() {
+    use system_info::network::sysctl;
+
+    let ip_forward = sysctl::ip_forward().expect("get ip_forward");
+    let expected = std::fs::read_to_string("/proc/sys/net/ipv4/ip_forward").expect("read ip_forward");
+    assert_eq!(ip_forward, expected.trim() != "0");
+
+    let range = sysctl::ip_local_port_range().expect("get port range");
+    assert!(range.start <= range.end);
+
+    assert!(sysctl::InterfaceConf::all().is_some());
+    assert!(sysctl::InterfaceConf::get("../../kernel").is_none());
+}
+
+#[cfg(any(target_os = "linux", target_os = "android"))]
+#[test]
+fn should_parse_network_stats() {
+    use system_info::network::{stats, SockStat, Snmp, Netstat};
+
+    const SOCKSTAT: &[u8] = b"sockets: used 301\nTCP: inuse 5 orphan 1 tw 2 alloc 7 mem 3\nUDP: inuse 2 mem 4\nUDPLITE: inuse 0\nRAW: inuse 0\nFRAG: inuse 0 memory 0\n";
+    const SOCKSTAT6: &[u8] = b"TCP6: inuse 6\nUDP6: inuse 8\nUDPLITE6: inuse 0\nRAW6: inuse 0\nFRAG6: inuse 0 memory 0\n";
+    const SNMP: &[u8] = b"Ip: Forwarding DefaultTTL InReceives InHdrErrors\nIp: 2 64 1000 3\nTcp: RtoAlgorithm MaxConn ActiveOpens RetransSegs InErrs\nTcp: 1 -1 10 42 5\nUdp: InDatagrams NoPorts InErrors OutDatagrams\nUdp: 100 2 7 90\n";
+    const NETSTAT: &[u8] = b"TcpExt: SyncookiesSent ListenOverflows ListenDrops\nTcpExt: 1 12 13\nIpExt: InNoRoutes\nIpExt: 0\n";
+
+    let sockstat = SockStat::parse(SOCKSTAT, SOCKSTAT6);
+    assert_eq!(sockstat.used, 301);
+    assert_eq!(sockstat.tcp.inuse, 5);
+    assert_eq!(sockstat.tcp.orphan, 1);
+    assert_eq!(sockstat.tcp.tw, 2);
+    assert_eq!(sockstat.tcp.alloc, 7);
+    assert_eq!(sockstat.tcp.mem, 3);
+    assert_eq!(sockstat.udp_inuse, 2);
+    assert_eq!(sockstat.udp_mem, 4);
+    assert_eq!(sockstat.tcp6_inuse, 6);
+    assert_eq!(sockstat.udp6_inuse, 8);
+
+    let snmp = Snmp::parse(SNMP);
+    assert_eq!(snmp.ip.in_receives, 1000);
+    assert_eq!(snmp.ip.in_hdr_errors, 3);
+    assert_eq!(snmp.tcp.active_opens, 10);
+    assert_eq!(snmp.tcp.retrans_segs, 42);
+    assert_eq!(snmp.tcp.in_errs, 5);
+    assert_eq!(snmp.udp.in_datagrams, 100);
+    assert_eq!(snmp.udp.in_errors, 7);
+
+    let netstat = Netstat::parse(NETSTAT);
+    assert_eq!(netstat.syncookies_sent, 1);
+    assert_eq!(netstat.listen_overflows, 12);
+    assert_eq!(netstat.listen_drops, 13);
+
+    //Stray line must not misalign following sections
+    let snmp = Snmp::parse(b"Ip: Forwarding InReceives\nIcmp: InMsgs\nIcmp: 4\nTcp: ActiveOpens\nTcp: 10\nUdp: InDatagrams\nUdp: 100\n");
+    assert_eq!(snmp.ip.in_receives, 0);
+    assert_eq!(snmp.tcp.active_opens, 10);
+    assert_eq!(snmp.udp.in_datagrams, 100);
+    let netstat = Netstat::parse(b"TcpExt: ListenDrops\nIpExt: InNoRoutes\nIpExt: 0\nTcpExt: ListenOverflows\nTcpExt: 12\n");
+    assert_eq!(netstat.listen_drops, 0);
+    assert_eq!(netstat.listen_overflows, 12);
+
+    assert!(stats().is_some());
+}
+
+#[cfg(any(target_os = "linux", target_os = "android"))]
+#[test]
+fn should_not_find_bond_on_loopback() {
+    use system_info::network::bridge_fdb;
+
+    let interfaces = Interfaces::new().expect("get interfaces");
+    let lo = interfaces.iter().find(|interface| interface.name().as_deref() == Some("lo")).expect("find loopback");
+    let index = lo.index().expect("get loopback index");
+
+    assert!(lo.bond_info().is_none());
+    let fdb = bridge_fdb(index).expect("dump fdb");
+    assert!(fdb.iter().all(|entry| entry.port == index || entry.master == Some(index)));
+}
+
+#[cfg(target_os = "linux")]
+#[test]
+fn should_get_bridge_fdb_and_bond_info() {
+    use system_info::network::{bridge_fdb, BondMode};
+
+    const NAMESPACE_ENV: &str = "SYSTEM_INFO_TEST_NETNS";
+    const BOND_ENV: &str = "SYSTEM_INFO_TEST_BOND";
+    const BRIDGE_SETUP: &str = "ip link add br0 type bridge && ip link add v0 type veth peer name v1 && ip link set v0 master br0 \
+                                && ip link set v0 up && ip link set br0 up && ip addr add 10.0.0.1/24 dev br0";
+    const BOND_SETUP: &str = " && ip link add bond0 type bond mode active-backup miimon 100 && ip link add v2 type veth peer name v3 \
+                              && ip link set v2 master bond0 && ip link set bond0 up && ip addr add 10.0.1.1/24 dev bond0";
+    const RUN: &str = " || exit 77; exec \"$0\" --exact should_get_bridge_fdb_and_bond_info --nocapture";
+
+    if std::env::var_os(NAMESPACE_ENV).is_none() {
+        match std::process::Command::new("unshare").arg("-Urn").arg("true").status() {
+            Ok(status) if status.success() => (),
+            Ok(status) => return println!("Skip: cannot create user and network namespace: {}", status),
+            Err(error) => return println!("Skip: cannot run unshare: {}", error),
+        }
+
+        //Bonding driver cannot be loaded from within user namespace
+        let has_bond = std::path::Path::new("/sys/module/bonding").exists();
+        if !has_bond {
+            println!("Skip: bonding driver is not loaded, checking bridge only");
+        }
+        let setup = match has_bond {
+            true => format!("{}{}{}", BRIDGE_SETUP, BOND_SETUP, RUN),
+            false => format!("{}{}", BRIDGE_SETUP, RUN),
+        };
+
+        //Re-run itself inside user and network namespace with bridge and bond
+        let exe = std::env::current_exe().expect("get test executable");
+        let mut command = std::process::Command::new("unshare");
+        command.arg("-Urn").arg("sh").arg("-c").arg(setup).arg(exe).env(NAMESPACE_ENV, "1");
+        if has_bond {
+            command.env(BOND_ENV, "1");
+        }
+
+        let status = command.status().expect("run unshare");
+        assert_ne!(status.code(), Some(77), "Cannot create bridge and bond in namespace");
+        assert!(status.success(), "Namespace test failed: {}", status);
+        return;
+    }
+
+    let interfaces = Interfaces::new().expect("get interfaces");
+    let bridge = interfaces.iter().find(|interface| interface.name().as_deref() == Some("br0")).expect("find br0");
+    let bridge_index = bridge.index().expect("get br0 index");
+    let fdb = bridge_fdb(bridge_index).expect("dump fdb");
+    assert!(fdb.iter().any(|entry| entry.master == Some(bridge_index) && entry.port != bridge_index && entry.is_permanent()));
+    assert!(bridge.bond_info().is_none());
+
+    if std::env::var_os(BOND_ENV).is_none() {
+        return;
+    }
+
+    let bond = interfaces.iter().find(|interface| interface.name().as_deref() == Some("bond0")).expect("find bond0");
+    let info = bond.bond_info().expect("get bond info");
+    assert_eq!(info.mode, BondMode::ActiveBackup);
+    assert_eq!(info.miimon, 100);
+    assert_eq!(info.slaves.len(), 1);
+    assert_eq!(info.slaves[0].name(), Some("v2"));
+}
+
+#[cfg(any(target_os = "linux", target_os = "android"))]
+#[test]
+fn should_detect_wireless_from_fixture() {
+    use system_info::network::{WirelessInfo, LinkQuality};
+
+    const PROC_WIRELESS: &str = "Inter-| sta-|   Quality        |   Discarded packets               | Missed | WE\n \
+                                 face | tus | link level noise |  nwid  crypt   frag  retry   misc | beacon | 22\n \
+                                 wlan0: 0000   70.  -40.  -256        0      0      0      0      0        0\n";
+
+    let root = common::fixture("wireless", &[
+        ("sys/class/net/wlan0/phy80211/name", "phy0\n"),
+        ("proc/net/wireless", PROC_WIRELESS),
+    ]);
+    root.create_dir("sys/class/net/eth0");
+
+    assert!(WirelessInfo::with_root(root.as_str(), "eth0").is_none());
+    assert!(WirelessInfo::with_root(root.as_str(), "../net/wlan0").is_none());
+
+    let info = WirelessInfo::with_root(root.as_str(), "wlan0").expect("get wlan0 info");
+    assert_eq!(info.phy(), Some("phy0"));
+    assert_eq!(info.ssid(), None);
+    assert_eq!(info.link, Some(LinkQuality {
+        status: 0,
+        link: 70,
+        level: -40,
+        noise: -256,
+    }));
+    assert!(LinkQuality::parse(PROC_WIRELESS.as_bytes(), "wlan1").is_none());
+
+    let interfaces = Interfaces::new().expect("get interfaces");
+    for interface in interfaces.iter() {
+        assert_eq!(interface.is_wireless(), interface.wireless_info().is_some());
+    }
+}
@@ -0,0 +1,66 @@
+use system_info::numa;
+
+mod common;
+
+#[test]
+fn should_get_numa_nodes() {
+    let nodes = numa::nodes();
+    assert!(!nodes.is_empty());
+    assert!(nodes.iter().any(|node| !node.cpus.is_empty()));
+    for node in nodes.iter() {
+        assert_eq!(node.distance(node.id), Some(numa::LOCAL_DISTANCE));
+    }
+}
+
+#[cfg(target_os = "linux")]
+#[test]
+fn should_read_numa_fixture() {
+    use system_info::cpu::CpuSet;
+
+    fn nodes(name: &str, files: &[(&str, &str)]) -> Vec<numa::Node> {
+        let root = common::fixture(&format!("numa-{}", name), files);
+        numa::nodes_with_root(root.as_str())
+    }
+
+    let result = nodes("multi", &[
+        ("sys/devices/system/node/online", "0-1,3\n"),
+        ("sys/devices/system/node/node0/cpulist", "0-3,8-11\n"),
+        ("sys/devices/system/node/node0/meminfo", "Node 0 MemTotal:       16314724 kB\nNode 0 MemFree:         8157362 kB\nNode 0 MemUsed:         8157362 kB\n"),
+        ("sys/devices/system/node/node0/distance", "10 21 31\n"),
+        ("sys/devices/system/node/node1/cpulist", "4-7,12-15\n"),
+        ("sys/devices/system/node/node1/meminfo", "Node 1 MemTotal:       16777216 kB\nNode 1 MemFree:         1048576 kB\n"),
+        ("sys/devices/system/node/node1/distance", "21 10 31\n"),
+        //Memory-only node
+        ("sys/devices/system/node/node3/cpulist", "\n"),
+        ("sys/devices/system/node/node3/meminfo", "Node 3 MemTotal:       4194304 kB\nNode 3 MemFree:        4194304 kB\n"),
+        ("sys/devices/system/node/node3/distance", "31 31 10\n"),
+    ]);
+
+    assert_eq!(result.len(), 3);
+    assert_eq!(result[0].id, 0);
+    assert_eq!(result[0].cpus, CpuSet::parse("0-3,8-11").unwrap());
+    assert_eq!(result[0].total_memory, 16314724 * 1024);
+    assert_eq!(result[0].free_memory, 8157362 * 1024);
+    assert_eq!(result[0].distances, [(0, 10), (1, 21), (3, 31)]);
+
+    assert_eq!(result[1].id, 1);
+    assert_eq!(result[1].cpus, CpuSet::parse("4-7,12-15").unwrap());
+    assert_eq!(result[1].free_memory, 1048576 * 1024);
+    assert_eq!(result[1].distance(0), Some(21));
+    assert_eq!(result[1].distance(1), Some(10));
+    assert_eq!(result[1].distance(2), None);
+
+    assert_eq!(result[2].id, 3);
+    assert!(result[2].cpus.is_empty());
+    assert_eq!(result[2].total_memory, 4194304 * 1024);
+    assert_eq!(result[2].distance(3), Some(10));
+
+    let result = nodes("single", &[
+        ("sys/devices/system/cpu/online", "0-5\n"),
+    ]);
+    assert_eq!(result.len(), 1);
+    assert_eq!(result[0].id, 0);
+    assert_eq!(result[0].cpus, CpuSet::parse("0-5").unwrap());
+    assert_eq!(result[0].distances, [(0, numa::LOCAL_DISTANCE)]);
+    assert_ne!(result[0].total_memory, 0);
+}